@@ -0,0 +1,12 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    
+    // Generated gRPC service code for the `grpc` feature; protoc is vendored
+    // so the build doesn't depend on a system install
+    #[cfg(feature = "grpc")]
+    {
+        let protoc = protoc_bin_vendored::protoc_bin_path().expect("vendored protoc is available");
+        std::env::set_var("PROTOC", protoc);
+        tonic_build::compile_protos("proto/monitoring.proto").expect("failed to compile proto/monitoring.proto");
+    }
+}
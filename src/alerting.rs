@@ -0,0 +1,814 @@
+//! Alert delivery
+//!
+//! Posts a JSON payload to the configured webhook whenever a model
+//! transitions into `Degraded`, `Critical` or `Offline`, or recovers from
+//! any of them.
+//! When more than `alert_batch_threshold` models enter the same status in
+//! one batch of scores, a single `AggregateAlert` is sent instead. A global
+//! token bucket caps deliveries at `alert_rate_limit_per_minute`.
+//!
+//! Each alert goes to the webhook in `alert_routes` for its `Severity`,
+//! falling back to `alert_webhook`, so warnings and pages can land in
+//! different channels.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, warn};
+use uuid::Uuid;
+
+use crate::{sign_payload, ModelPerformanceMonitoringPlugin, ModelStatus, MonitoredModel, PluginConfig, SIGNATURE_HEADER};
+
+/// Retry schedule for webhook deliveries: the delay doubles from `base`
+/// after each failed attempt, up to `max`, and delivery is given up after
+/// `max_attempts`. Each delay is jittered so retries of many alerts don't
+/// hit a struggling endpoint in lockstep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackoffPolicy {
+    pub base: Duration,
+    pub max: Duration,
+    pub max_attempts: u32,
+}
+
+impl BackoffPolicy {
+    /// Delay before retry `n` (1 after the first failed attempt), without
+    /// jitter: `base * 2^(n - 1)` capped at `max`
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.base.saturating_mul(factor).min(self.max)
+    }
+    
+    /// `delay(retry)` with its upper half randomized, so it falls in
+    /// `[delay / 2, delay]`
+    pub fn jittered_delay(&self, retry: u32, rng: &mut impl rand::Rng) -> Duration {
+        let delay = self.delay(retry);
+        let floor = delay / 2;
+        floor + rng.random_range(Duration::ZERO..=delay - floor)
+    }
+}
+
+/// Time of the last alert sent per model and new status
+pub(crate) type AlertLog = HashMap<(Uuid, ModelStatus), DateTime<Utc>>;
+
+/// Token bucket holding up to `alert_rate_limit_per_minute` tokens, refilled
+/// continuously at that rate; each delivery takes one token
+#[derive(Debug)]
+pub(crate) struct AlertRateLimiter {
+    tokens: f64,
+    refilled_at: Instant,
+    dropped: u64,
+}
+
+impl AlertRateLimiter {
+    pub(crate) fn new() -> Self {
+        Self {
+            // Clamped to the configured rate on first use, so the bucket starts full
+            tokens: f64::INFINITY,
+            refilled_at: Instant::now(),
+            dropped: 0,
+        }
+    }
+    
+    /// Take a token if one is available, otherwise count a dropped alert
+    fn try_acquire(&mut self, per_minute: u32, now: Instant) -> bool {
+        if per_minute == 0 {
+            return true;
+        }
+        
+        let capacity = f64::from(per_minute);
+        let elapsed = now.saturating_duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * capacity / 60.0).min(capacity);
+        self.refilled_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            self.dropped += 1;
+            false
+        }
+    }
+}
+
+/// Shape of the webhook payload
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AlertFormat {
+    /// The `Alert` struct serialized as JSON
+    #[default]
+    Raw,
+    /// A Slack incoming-webhook message: `{"text": "..."}`
+    Slack,
+}
+
+/// How urgently an alert needs attention, used to pick its route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Severity {
+    /// Recoveries
+    Info,
+    /// Models entering `Degraded`
+    Warning,
+    /// Models entering `Critical` or `Offline`
+    Critical,
+}
+
+impl Severity {
+    /// Severity of an alert about a model entering `status`
+    pub fn of(status: &ModelStatus) -> Self {
+        match status {
+            ModelStatus::Critical | ModelStatus::Offline => Severity::Critical,
+            ModelStatus::Degraded => Severity::Warning,
+            ModelStatus::Healthy | ModelStatus::Unknown => Severity::Info,
+        }
+    }
+}
+
+/// Payload sent to the alert webhook on a status transition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Alert {
+    pub model_id: Uuid,
+    pub name: String,
+    pub old_status: ModelStatus,
+    pub new_status: ModelStatus,
+    pub score: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Alert {
+    /// Build an alert for `model`, which has just left `old_status`
+    pub fn new(model: &MonitoredModel, old_status: ModelStatus) -> Self {
+        Self {
+            model_id: model.id,
+            name: model.name.clone(),
+            old_status,
+            new_status: model.status.clone(),
+            score: model.performance_score,
+            timestamp: model.last_check,
+        }
+    }
+    
+    /// Whether this transition is one operators should be alerted about:
+    /// entering `Degraded`/`Critical`/`Offline`, or recovering from any of them
+    pub fn is_actionable(&self) -> bool {
+        match self.new_status {
+            ModelStatus::Degraded | ModelStatus::Critical | ModelStatus::Offline => true,
+            ModelStatus::Healthy => self.old_status > ModelStatus::Healthy,
+            ModelStatus::Unknown => false,
+        }
+    }
+    
+    /// Render the webhook request body in the given format
+    pub fn render(&self, format: AlertFormat) -> String {
+        match format {
+            AlertFormat::Raw => serde_json::to_string(self).unwrap_or_default(),
+            AlertFormat::Slack => serde_json::json!({ "text": self.slack_text() }).to_string(),
+        }
+    }
+    
+    fn slack_text(&self) -> String {
+        let verb = if self.new_status == ModelStatus::Healthy { "recovered to" } else { "is" };
+        format!(
+            "{} *{}* {} {:?} (score {:.2}, was {:?})",
+            status_emoji(&self.new_status), self.name, verb, self.new_status, self.score, self.old_status
+        )
+    }
+}
+
+/// Payload sent in place of individual alerts when many models enter the
+/// same status at once, e.g. because a shared dependency failed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AggregateAlert {
+    pub new_status: ModelStatus,
+    pub count: usize,
+    pub model_ids: Vec<Uuid>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl AggregateAlert {
+    /// Combine `alerts`, which must all share the same `new_status`
+    fn new(new_status: ModelStatus, alerts: &[Alert]) -> Self {
+        Self {
+            new_status,
+            count: alerts.len(),
+            model_ids: alerts.iter().map(|a| a.model_id).collect(),
+            timestamp: alerts.iter().map(|a| a.timestamp).max().unwrap_or_else(Utc::now),
+        }
+    }
+    
+    /// Render the webhook request body in the given format
+    pub fn render(&self, format: AlertFormat) -> String {
+        match format {
+            AlertFormat::Raw => serde_json::to_string(self).unwrap_or_default(),
+            AlertFormat::Slack => serde_json::json!({ "text": self.slack_text() }).to_string(),
+        }
+    }
+    
+    fn slack_text(&self) -> String {
+        let verb = if self.new_status == ModelStatus::Healthy { "recovered to" } else { "are" };
+        format!("{} {} models {} {:?}", status_emoji(&self.new_status), self.count, verb, self.new_status)
+    }
+}
+
+fn status_emoji(status: &ModelStatus) -> &'static str {
+    match status {
+        ModelStatus::Healthy => "✅",
+        ModelStatus::Degraded => "⚠️",
+        ModelStatus::Critical => "🔴",
+        ModelStatus::Offline => "📴",
+        ModelStatus::Unknown => "❔",
+    }
+}
+
+/// Render the alert body for `model` moving from `old` to `new`
+pub fn format_alert(model: &MonitoredModel, old: &ModelStatus, new: &ModelStatus, fmt: AlertFormat) -> String {
+    Alert {
+        model_id: model.id,
+        name: model.name.clone(),
+        old_status: old.clone(),
+        new_status: new.clone(),
+        score: model.performance_score,
+        timestamp: model.last_check,
+    }
+    .render(fmt)
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Send the alerts for one batch of transitions to their routes in the
+    /// background.
+    ///
+    /// Nothing is sent during a maintenance window. Otherwise, alerts still
+    /// held back by the cooldown are dropped first. If more than
+    /// `alert_batch_threshold` of the rest share a new status, they are sent
+    /// as one `AggregateAlert`. Deliveries over the global rate limit are
+    /// dropped. Delivery never blocks or fails the caller; errors are logged.
+    pub(crate) fn dispatch_alerts(&self, config: &PluginConfig, alerts: Vec<Alert>) {
+        if !config.alert_enabled || (config.alert_webhook.is_none() && config.alert_routes.is_empty()) {
+            return;
+        }
+        if config.in_maintenance(self.now()) {
+            debug!("Suppressing {} alerts during a maintenance window", alerts.len());
+            return;
+        }
+        
+        let mut by_status: Vec<(ModelStatus, Vec<Alert>)> = Vec::new();
+        for alert in alerts.into_iter().filter(Alert::is_actionable) {
+            if !self.cooldown_elapsed(config, &alert) {
+                debug!(model_id = %alert.model_id, "Suppressing repeated {:?} alert during cooldown", alert.new_status);
+                continue;
+            }
+            match by_status.iter_mut().find(|(status, _)| *status == alert.new_status) {
+                Some((_, group)) => group.push(alert),
+                None => by_status.push((alert.new_status.clone(), vec![alert])),
+            }
+        }
+        
+        for (status, group) in by_status {
+            let Some(url) = config.alert_route(Severity::of(&status)) else {
+                debug!("No route for {:?} alerts, dropping {}", Severity::of(&status), group.len());
+                continue;
+            };
+            if config.alert_batch_threshold > 0 && group.len() > config.alert_batch_threshold {
+                let aggregate = AggregateAlert::new(status, &group);
+                let subject = format!("{} {:?} models", aggregate.count, aggregate.new_status);
+                if self.spawn_delivery(config, url, subject, aggregate.render(config.alert_format)) {
+                    self.start_cooldown(&group);
+                }
+            } else {
+                for alert in group {
+                    if self.spawn_delivery(config, url, alert.model_id.to_string(), alert.render(config.alert_format)) {
+                        self.start_cooldown([&alert]);
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Number of alerts dropped by the global rate limit since startup
+    pub fn dropped_alerts(&self) -> u64 {
+        self.alert_limiter.lock().unwrap().dropped
+    }
+    
+    /// Number of alerts given up on after `alert_max_attempts` failed deliveries
+    pub fn failed_alert_deliveries(&self) -> u64 {
+        self.failed_deliveries.load(Ordering::Relaxed)
+    }
+    
+    /// Start delivering an alert unless the global rate limit drops it,
+    /// returning whether it was accepted
+    fn spawn_delivery(&self, config: &PluginConfig, url: &str, subject: String, body: String) -> bool {
+        if !self.alert_limiter.lock().unwrap().try_acquire(config.alert_rate_limit_per_minute, Instant::now()) {
+            warn!(alert = %subject, "Dropping alert: over {} alerts per minute", config.alert_rate_limit_per_minute);
+            return false;
+        }
+        
+        let client = self.http_client.clone();
+        let url = url.to_string();
+        let signature = config.alert_signing_secret.as_ref().map(|secret| sign_payload(secret, body.as_bytes()));
+        let backoff = config.alert_backoff();
+        let failures = self.failed_deliveries.clone();
+        tokio::spawn(async move {
+            if !deliver(&client, &url, &subject, body, signature, backoff).await {
+                failures.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        true
+    }
+}
+
+impl PluginConfig {
+    /// Webhook URL for alerts of `severity`: its entry in `alert_routes`,
+    /// otherwise `alert_webhook`
+    pub fn alert_route(&self, severity: Severity) -> Option<&str> {
+        self.alert_routes.get(&severity).or(self.alert_webhook.as_ref()).map(String::as_str)
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Whether `alert` may go out under `alert_cooldown_minutes`
+    fn cooldown_elapsed(&self, config: &PluginConfig, alert: &Alert) -> bool {
+        if alert.new_status == ModelStatus::Healthy {
+            return true;
+        }
+        
+        let cooldown = chrono::Duration::minutes(i64::from(config.alert_cooldown_minutes));
+        let last_alerts = self.last_alerts.lock().unwrap();
+        let key = (alert.model_id, alert.new_status.clone());
+        last_alerts.get(&key).is_none_or(|last| alert.timestamp - *last >= cooldown)
+    }
+    
+    /// Start the cooldown for `alerts`, once they are handed off for delivery
+    fn start_cooldown<'a>(&self, alerts: impl IntoIterator<Item = &'a Alert>) {
+        let mut last_alerts = self.last_alerts.lock().unwrap();
+        for alert in alerts.into_iter().filter(|a| a.new_status != ModelStatus::Healthy) {
+            last_alerts.insert((alert.model_id, alert.new_status.clone()), alert.timestamp);
+        }
+    }
+}
+
+/// POST a rendered alert body to `url`, signed if a signature is given,
+/// retrying per `backoff`
+async fn deliver(
+    client: &reqwest::Client,
+    url: &str,
+    subject: &str,
+    body: String,
+    signature: Option<String>,
+    backoff: BackoffPolicy,
+) -> bool {
+    for attempt in 1..=backoff.max_attempts {
+        let mut request = client.post(url).header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(signature) = &signature {
+            request = request.header(SIGNATURE_HEADER, format!("sha256={}", signature));
+        }
+        let result = request
+            .body(body.clone())
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        
+        match result {
+            Ok(_) => {
+                debug!(alert = subject, "Delivered alert");
+                return true;
+            }
+            Err(e) if attempt < backoff.max_attempts => {
+                let delay = backoff.jittered_delay(attempt, &mut rand::rng());
+                warn!(alert = subject, attempt, "Alert delivery failed, retrying in {:?}: {}", delay, e);
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => {
+                error!(alert = subject, "Giving up on alert delivery after {} attempts: {}", attempt, e);
+            }
+        }
+    }
+    
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, mock_http_server};
+    use rand::SeedableRng;
+
+    #[tokio::test]
+    async fn test_degraded_transition_posts_alert() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("alerting".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.8).await.unwrap();
+        
+        let request = requests.recv().await.unwrap();
+        assert_eq!(request.request_line, "POST /hook HTTP/1.1");
+        let alert: Alert = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(alert.model_id, id);
+        assert_eq!(alert.old_status, ModelStatus::Unknown);
+        assert_eq!(alert.new_status, ModelStatus::Degraded);
+        assert_eq!(alert.score, 0.8);
+    }
+
+    #[tokio::test]
+    async fn test_critical_alert_routes_to_paging_url_only() {
+        let (paging, mut paged) = mock_http_server(200).await;
+        let (low_priority, mut warned) = mock_http_server(200).await;
+        let (fallback, mut defaulted) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/default", fallback)),
+            alert_routes: HashMap::from([
+                (Severity::Critical, format!("http://{}/page", paging)),
+                (Severity::Warning, format!("http://{}/warn", low_priority)),
+            ]),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("routed".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        
+        let request = paged.recv().await.unwrap();
+        assert_eq!(request.request_line, "POST /page HTTP/1.1");
+        let alert: Alert = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(alert.new_status, ModelStatus::Critical);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(warned.try_recv().is_err());
+        assert!(defaulted.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unrouted_severity_falls_back_to_default_webhook() {
+        let (paging, mut paged) = mock_http_server(200).await;
+        let (fallback, mut defaulted) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/default", fallback)),
+            alert_routes: HashMap::from([(Severity::Critical, format!("http://{}/page", paging))]),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("routed".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.8).await.unwrap();
+        
+        assert_eq!(defaulted.recv().await.unwrap().request_line, "POST /default HTTP/1.1");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(paged.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_alert_routes_parse_from_toml() {
+        let config: PluginConfig = toml::from_str("[alert_routes]\nCritical = \"https://pager\"\n").unwrap();
+        assert_eq!(config.alert_route(Severity::Critical), Some("https://pager"));
+        assert_eq!(config.alert_route(Severity::Warning), None);
+    }
+
+    fn backoff(base_ms: u64, max_ms: u64, max_attempts: u32) -> BackoffPolicy {
+        BackoffPolicy {
+            base: Duration::from_millis(base_ms),
+            max: Duration::from_millis(max_ms),
+            max_attempts,
+        }
+    }
+
+    #[test]
+    fn test_backoff_sequence() {
+        let policy = backoff(500, 5000, 6);
+        let delays: Vec<u64> = (1..policy.max_attempts).map(|n| policy.delay(n).as_millis() as u64).collect();
+        assert_eq!(delays, vec![500, 1000, 2000, 4000, 5000]);
+        assert_eq!(policy.delay(100), Duration::from_millis(5000));
+        
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        for retry in 1..10 {
+            let delay = policy.delay(retry);
+            let jittered = policy.jittered_delay(retry, &mut rng);
+            assert!(jittered >= delay / 2 && jittered <= delay, "{:?} outside {:?}", jittered, delay);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delivery_retries_then_gives_up() {
+        let (addr, mut requests) = mock_http_server(500).await;
+        let url = format!("http://{}/", addr);
+        
+        let delivered = deliver(&reqwest::Client::new(), &url, "test", "{}".to_string(), None, backoff(10, 40, 4)).await;
+        
+        assert!(!delivered);
+        for _ in 0..4 {
+            assert!(requests.recv().await.is_some());
+        }
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_is_counted() {
+        let (addr, mut requests) = mock_http_server(503).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            alert_retry_base_ms: 10,
+            alert_max_attempts: 2,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("flaky-endpoint".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        for _ in 0..2 {
+            requests.recv().await.unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        
+        assert_eq!(plugin.failed_alert_deliveries(), 1);
+        assert!(plugin.prometheus_metrics().await.contains("adios_alert_delivery_failures_total 1\n"));
+    }
+
+    fn sample_model() -> MonitoredModel {
+        test_support::sample_model("ranker", 0.42, ModelStatus::Critical)
+    }
+
+    #[test]
+    fn test_actionable_transitions() {
+        let model = sample_model();
+        let alert = |old: ModelStatus, new: ModelStatus| Alert { old_status: old, new_status: new, ..Alert::new(&model, ModelStatus::Unknown) };
+        
+        assert!(alert(ModelStatus::Healthy, ModelStatus::Critical).is_actionable());
+        assert!(alert(ModelStatus::Unknown, ModelStatus::Degraded).is_actionable());
+        assert!(alert(ModelStatus::Critical, ModelStatus::Healthy).is_actionable());
+        assert!(!alert(ModelStatus::Unknown, ModelStatus::Healthy).is_actionable());
+    }
+
+    #[test]
+    fn test_format_alert_slack() {
+        let model = sample_model();
+        
+        let body = format_alert(&model, &ModelStatus::Healthy, &ModelStatus::Critical, AlertFormat::Slack);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["text"], "🔴 *ranker* is Critical (score 0.42, was Healthy)");
+        
+        let body = format_alert(&model, &ModelStatus::Healthy, &ModelStatus::Degraded, AlertFormat::Slack);
+        assert!(body.contains("⚠️"));
+        
+        let body = format_alert(&model, &ModelStatus::Critical, &ModelStatus::Healthy, AlertFormat::Slack);
+        assert!(body.contains("✅ *ranker* recovered to Healthy"));
+    }
+
+    #[test]
+    fn test_format_alert_raw() {
+        let model = sample_model();
+        
+        let body = format_alert(&model, &ModelStatus::Healthy, &ModelStatus::Critical, AlertFormat::Raw);
+        let alert: Alert = serde_json::from_str(&body).unwrap();
+        assert_eq!(alert.model_id, model.id);
+        assert_eq!(alert.old_status, ModelStatus::Healthy);
+        assert_eq!(alert.new_status, ModelStatus::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_alerts_are_held_back_during_cooldown() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            auto_remediation: false,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("flapping".to_string(), "llm".to_string()).await.unwrap();
+        
+        // Critical, Degraded, Critical again, then a recovery
+        for score in [0.1, 0.7, 0.1, 0.7, 0.1, 0.9] {
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        
+        let mut statuses = Vec::new();
+        while let Ok(Some(request)) = tokio::time::timeout(Duration::from_millis(300), requests.recv()).await {
+            let alert: Alert = serde_json::from_str(&request.body).unwrap();
+            statuses.push(alert.new_status);
+        }
+        statuses.sort_by_key(|status| format!("{:?}", status));
+        assert_eq!(statuses, vec![ModelStatus::Critical, ModelStatus::Degraded, ModelStatus::Healthy]);
+    }
+
+    #[tokio::test]
+    async fn test_unrouted_alert_does_not_start_cooldown() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_routes: HashMap::from([(Severity::Critical, format!("http://{}/page", addr))]),
+            auto_remediation: false,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("unrouted".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.7).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        // Warnings now have somewhere to go, and the dropped one holds nothing back
+        plugin.update_config(PluginConfig {
+            alert_routes: HashMap::from([(Severity::Warning, format!("http://{}/warn", addr))]),
+            auto_remediation: false,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        plugin.record_performance(id, 0.7).await.unwrap();
+        
+        let request = tokio::time::timeout(Duration::from_secs(5), requests.recv()).await.unwrap().unwrap();
+        assert_eq!(request.request_line, "POST /warn HTTP/1.1");
+        let alert: Alert = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(alert.new_status, ModelStatus::Degraded);
+    }
+
+    #[tokio::test]
+    async fn test_recovery_ignores_cooldown() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            auto_remediation: false,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("recovering".to_string(), "llm".to_string()).await.unwrap();
+        
+        for score in [0.7, 0.9, 0.7, 0.9] {
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        
+        let mut recoveries = 0;
+        while let Ok(Some(request)) = tokio::time::timeout(Duration::from_millis(300), requests.recv()).await {
+            let alert: Alert = serde_json::from_str(&request.body).unwrap();
+            recoveries += usize::from(alert.new_status == ModelStatus::Healthy);
+        }
+        assert_eq!(recoveries, 2);
+    }
+
+    #[tokio::test]
+    async fn test_check_cycle_tripping_many_models_sends_one_aggregate_alert() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            alert_batch_threshold: 10,
+            auto_remediation: false,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let mut source = crate::StaticSource::new();
+        let mut ids = Vec::new();
+        for i in 0..15 {
+            let name = format!("model-{}", i);
+            ids.push(plugin.add_model(name.clone(), "llm".to_string()).await.unwrap());
+            source = source.with_score(name, 0.2);
+        }
+        plugin.set_metrics_source(source).await;
+        
+        plugin.run_checks().await;
+        
+        let request = requests.recv().await.unwrap();
+        let aggregate: AggregateAlert = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(aggregate.new_status, ModelStatus::Critical);
+        assert_eq!(aggregate.count, 15);
+        let mut alerted = aggregate.model_ids.clone();
+        alerted.sort();
+        ids.sort();
+        assert_eq!(alerted, ids);
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_transitions_below_batch_threshold_alert_individually() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            alert_batch_threshold: 3,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let mut samples = Vec::new();
+        for i in 0..3 {
+            let id = plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap();
+            samples.push((id, 0.8));
+        }
+        
+        plugin.record_performance_batch(samples).await;
+        
+        for _ in 0..3 {
+            let request = requests.recv().await.unwrap();
+            let alert: Alert = serde_json::from_str(&request.body).unwrap();
+            assert_eq!(alert.new_status, ModelStatus::Degraded);
+        }
+    }
+
+    #[test]
+    fn test_aggregate_alert_slack() {
+        let model = sample_model();
+        let alerts = vec![Alert::new(&model, ModelStatus::Healthy); 12];
+        
+        let body = AggregateAlert::new(ModelStatus::Degraded, &alerts).render(AlertFormat::Slack);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["text"], "⚠️ 12 models are Degraded");
+    }
+
+    #[tokio::test]
+    async fn test_paused_critical_model_emits_no_alerts() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            alert_cooldown_minutes: 0,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("maintenance".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.2).await.unwrap();
+        assert!(requests.recv().await.is_some());
+        
+        plugin.pause_model(id).await.unwrap();
+        assert!(matches!(plugin.record_performance(id, 0.9).await, Err(crate::Error::ModelPaused(_))));
+        assert!(matches!(plugin.record_performance(id, 0.1).await, Err(crate::Error::ModelPaused(_))));
+        plugin.set_metrics_source(crate::StaticSource::new().with_score("maintenance", 0.95)).await;
+        plugin.run_checks().await;
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(requests.try_recv().is_err());
+        let model = plugin.get_model(id).await.unwrap();
+        assert_eq!((model.status, model.performance_score), (ModelStatus::Critical, 0.2));
+        
+        // Resuming keeps the last known score, and checking picks up again
+        plugin.resume_model(id).await.unwrap();
+        assert_eq!(plugin.get_model(id).await.unwrap().performance_score, 0.2);
+        plugin.run_checks().await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_alerts_over_rate_limit_are_dropped_and_counted() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            alert_rate_limit_per_minute: 10,
+            alert_batch_threshold: 0,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        for i in 0..11 {
+            let id = plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap();
+            plugin.record_performance(id, 0.8).await.unwrap();
+        }
+        
+        for _ in 0..10 {
+            assert!(requests.recv().await.is_some());
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(requests.try_recv().is_err());
+        assert_eq!(plugin.dropped_alerts(), 1);
+        assert!(plugin.prometheus_metrics().await.contains("adios_alerts_dropped_total 1\n"));
+        
+        // Only the delivered alerts hold back repeats; the dropped one doesn't
+        assert_eq!(plugin.last_alerts.lock().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_rate_limiter_refills_over_time() {
+        let mut limiter = AlertRateLimiter::new();
+        let start = Instant::now();
+        
+        for _ in 0..10 {
+            assert!(limiter.try_acquire(10, start));
+        }
+        assert!(!limiter.try_acquire(10, start));
+        
+        // One token every 6 seconds at 10 per minute
+        assert!(limiter.try_acquire(10, start + Duration::from_secs(6)));
+        assert!(!limiter.try_acquire(10, start + Duration::from_secs(7)));
+        assert!(limiter.try_acquire(0, start + Duration::from_secs(7)));
+        assert_eq!(limiter.dropped, 2);
+    }
+
+    #[tokio::test]
+    async fn test_critical_score_during_warmup_is_degraded_without_alert() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            warmup_minutes: 10,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("cold-cache".to_string(), "llm".to_string()).await.unwrap();
+        assert!(plugin.model_in_warmup(id).await.unwrap());
+        
+        assert_eq!(plugin.record_performance(id, 0.1).await.unwrap(), ModelStatus::Degraded);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(requests.try_recv().is_err());
+        assert!(plugin.get_model(id).await.unwrap().last_remediation.is_none());
+        
+        // Once warmup has elapsed, the same score is critical and alerts
+        plugin.state.write().await.model_mut(id).unwrap().created_at -= chrono::Duration::minutes(11);
+        assert!(!plugin.model_in_warmup(id).await.unwrap());
+        assert_eq!(plugin.record_performance(id, 0.1).await.unwrap(), ModelStatus::Critical);
+        let request = requests.recv().await.unwrap();
+        assert!(request.body.contains("Critical"));
+    }
+}
@@ -0,0 +1,468 @@
+//! Statistical analysis over per-model score history
+
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, ModelScore, MonitoredModel, PluginConfig};
+
+/// Comparison of a model's recent scores against its earlier baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DriftReport {
+    pub baseline_mean: f64,
+    pub recent_mean: f64,
+    /// Change of the recent mean relative to the baseline, in percent
+    pub percent_change: f64,
+    /// Whether the drop exceeds `config.drift_threshold`
+    pub drifting: bool,
+}
+
+/// How far a model's latest score sits from the distribution of its earlier history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AnomalyReport {
+    /// The sample being judged
+    pub latest: f64,
+    /// Mean of the samples before `latest`
+    pub mean: f64,
+    /// Population standard deviation of the samples before `latest`
+    pub stddev: f64,
+    /// `(latest - mean) / stddev`; infinite if the earlier samples are all
+    /// identical and `latest` differs from them
+    pub zscore: f64,
+    /// Whether `|zscore|` exceeds `config.anomaly_zscore`
+    pub anomalous: bool,
+}
+
+/// Direction a model's score history is heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Trend {
+    Improving,
+    Stable,
+    Declining,
+    /// Fewer than two samples of history to fit a slope to
+    Unknown,
+}
+
+/// Side-by-side view of two models, e.g. a candidate against a baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModelComparison {
+    pub a: ModelScore,
+    pub b: ModelScore,
+    /// `b.score - a.score`
+    pub delta: f64,
+    /// Id of the higher-scoring model, `None` when the scores are equal
+    pub better: Option<Uuid>,
+    /// Trend of `a`'s history, `None` with fewer than two samples
+    pub a_trend: Option<Trend>,
+    /// Trend of `b`'s history, `None` with fewer than two samples
+    pub b_trend: Option<Trend>,
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Fraction of monitored time the model has spent `Healthy`, see
+    /// `MonitoredModel::uptime_ratio`
+    pub async fn uptime_ratio(&self, id: Uuid) -> Result<f64, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(model.uptime_ratio(self.now()))
+    }
+    
+    /// Compare the most recent samples with the baseline window before them.
+    ///
+    /// Returns `None` until the model has at least
+    /// `drift_recent_window + drift_baseline_window` samples of history.
+    pub async fn detect_drift(&self, id: Uuid) -> Result<Option<DriftReport>, Error> {
+        let state = self.state.load();
+        match state.monitored_models.get(&id) {
+            Some(model) => Ok(drift_report(model, &state.config)),
+            None => Err(Error::ModelNotFound(id)),
+        }
+    }
+    
+    /// Judge the most recent sample against the rest of the model's history.
+    ///
+    /// Returns `None` until the model has at least `anomaly_min_samples`
+    /// samples of history.
+    pub async fn check_anomaly(&self, id: Uuid) -> Result<Option<AnomalyReport>, Error> {
+        let state = self.state.load();
+        match state.monitored_models.get(&id) {
+            Some(model) => Ok(anomaly_report(model, &state.config)),
+            None => Err(Error::ModelNotFound(id)),
+        }
+    }
+    
+    /// Direction of the model's last `trend_window` samples, `Unknown` with
+    /// fewer than two
+    pub async fn model_trend(&self, id: Uuid) -> Result<Trend, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(trend(model, &state.config).unwrap_or(Trend::Unknown))
+    }
+    
+    /// Least-squares slope, in score per sample, of the model's last
+    /// `trend_window` samples; `None` with fewer than two
+    pub async fn model_trend_slope(&self, id: Uuid) -> Result<Option<f64>, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(slope(model, state.config.trend_window))
+    }
+    
+    /// Compare the current scores and trends of two models
+    pub async fn compare_models(&self, a: Uuid, b: Uuid) -> Result<ModelComparison, Error> {
+        let state = self.state.load();
+        let model_a = state.monitored_models.get(&a).ok_or(Error::ModelNotFound(a))?;
+        let model_b = state.monitored_models.get(&b).ok_or(Error::ModelNotFound(b))?;
+        
+        let delta = model_b.performance_score - model_a.performance_score;
+        let better = match delta.partial_cmp(&0.0) {
+            Some(std::cmp::Ordering::Greater) => Some(b),
+            Some(std::cmp::Ordering::Less) => Some(a),
+            _ => None,
+        };
+        let score = |model: &MonitoredModel| ModelScore {
+            id: model.id,
+            name: model.name.clone(),
+            score: model.performance_score,
+        };
+        
+        Ok(ModelComparison {
+            a: score(model_a),
+            b: score(model_b),
+            delta,
+            better,
+            a_trend: trend(model_a, &state.config),
+            b_trend: trend(model_b, &state.config),
+        })
+    }
+}
+
+/// Trend of the recent history, classified against `trend_epsilon`
+pub(crate) fn trend(model: &MonitoredModel, config: &PluginConfig) -> Option<Trend> {
+    let slope = slope(model, config.trend_window)?;
+    Some(if slope > config.trend_epsilon {
+        Trend::Improving
+    } else if slope < -config.trend_epsilon {
+        Trend::Declining
+    } else {
+        Trend::Stable
+    })
+}
+
+/// Least-squares slope of the last `window` history samples against sample index
+pub(crate) fn slope(model: &MonitoredModel, window: usize) -> Option<f64> {
+    let n = window.min(model.history.len());
+    if n < 2 {
+        return None;
+    }
+    let recent = model.history.range(model.history.len() - n..);
+    
+    let mean_x = (n - 1) as f64 / 2.0;
+    let mean_y = recent.clone().map(|(_, score)| *score).sum::<f64>() / n as f64;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (i, (_, score)) in recent.enumerate() {
+        let dx = i as f64 - mean_x;
+        covariance += dx * (*score - mean_y);
+        variance += dx * dx;
+    }
+    
+    Some(covariance / variance)
+}
+
+fn anomaly_report(model: &MonitoredModel, config: &PluginConfig) -> Option<AnomalyReport> {
+    judge_sample(&model.history, model.history.len().checked_sub(1)?, config)
+}
+
+/// Anomalous samples among the last `window`, each judged against the
+/// history before it, oldest first
+pub(crate) fn recent_anomalies(
+    model: &MonitoredModel,
+    config: &PluginConfig,
+    window: usize,
+) -> Vec<(DateTime<Utc>, AnomalyReport)> {
+    let history = &model.history;
+    (history.len().saturating_sub(window)..history.len())
+        .filter_map(|index| Some((history[index].0, judge_sample(history, index, config)?)))
+        .filter(|(_, report)| report.anomalous)
+        .collect()
+}
+
+/// Differences between scores smaller than this are floating point noise
+const ROUNDING_TOLERANCE: f64 = 1e-12;
+
+/// Judge the sample at `index` against the samples before it, once there
+/// are `anomaly_min_samples` up to and including it
+fn judge_sample(history: &VecDeque<(DateTime<Utc>, f64)>, index: usize, config: &PluginConfig) -> Option<AnomalyReport> {
+    if index + 1 < config.anomaly_min_samples.max(2) {
+        return None;
+    }
+    let latest = history.get(index)?.1;
+    
+    // The judged sample is left out so an outlier can't inflate its own baseline
+    let earlier = history.range(..index).map(|(_, score)| *score);
+    let n = index as f64;
+    let mean = earlier.clone().sum::<f64>() / n;
+    let stddev = (earlier.map(|score| (score - mean).powi(2)).sum::<f64>() / n).sqrt();
+    
+    // Summing identical scores leaves rounding noise, which must not read as spread
+    let flat = |x: f64| if x.abs() < ROUNDING_TOLERANCE { 0.0 } else { x };
+    let (stddev, deviation) = (flat(stddev), flat(latest - mean));
+    let zscore = if stddev > 0.0 {
+        deviation / stddev
+    } else if deviation == 0.0 {
+        0.0
+    } else {
+        f64::INFINITY.copysign(deviation)
+    };
+    
+    Some(AnomalyReport {
+        latest,
+        mean,
+        stddev,
+        zscore,
+        anomalous: zscore.abs() > config.anomaly_zscore,
+    })
+}
+
+fn drift_report(model: &MonitoredModel, config: &PluginConfig) -> Option<DriftReport> {
+    let recent_len = config.drift_recent_window;
+    let baseline_len = config.drift_baseline_window;
+    let history = &model.history;
+    if history.len() < recent_len + baseline_len {
+        return None;
+    }
+    
+    let recent_start = history.len() - recent_len;
+    let baseline_start = recent_start - baseline_len;
+    let mean = |range: std::ops::Range<usize>| {
+        let len = range.len() as f64;
+        history.range(range).map(|(_, score)| *score).sum::<f64>() / len
+    };
+    let baseline_mean = mean(baseline_start..recent_start);
+    let recent_mean = mean(recent_start..history.len());
+    
+    let relative_change = if baseline_mean > 0.0 {
+        (recent_mean - baseline_mean) / baseline_mean
+    } else {
+        0.0
+    };
+    
+    Some(DriftReport {
+        baseline_mean,
+        recent_mean,
+        percent_change: relative_change * 100.0,
+        drifting: -relative_change > config.drift_threshold,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+
+    async fn plugin_with_history(scores: &[f64]) -> (ModelPerformanceMonitoringPlugin, Uuid) {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("drifty".to_string(), ModelType::Embedding).await.unwrap();
+        for &score in scores {
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        (plugin, id)
+    }
+
+    #[tokio::test]
+    async fn test_detect_drift() {
+        // 20 baseline samples at 0.95, then 5 recent samples at 0.80: a ~15.8% drop
+        let mut scores = vec![0.95; 20];
+        scores.extend([0.80; 5]);
+        let (plugin, id) = plugin_with_history(&scores).await;
+        
+        let report = plugin.detect_drift(id).await.unwrap().unwrap();
+        assert!((report.baseline_mean - 0.95).abs() < 1e-6);
+        assert!((report.recent_mean - 0.80).abs() < 1e-6);
+        assert!((report.percent_change + 15.789).abs() < 1e-2);
+        assert!(report.drifting);
+    }
+
+    #[tokio::test]
+    async fn test_small_drop_is_not_drift() {
+        let mut scores = vec![0.95; 20];
+        scores.extend([0.90; 5]);
+        let (plugin, id) = plugin_with_history(&scores).await;
+        
+        let report = plugin.detect_drift(id).await.unwrap().unwrap();
+        assert!(!report.drifting);
+    }
+
+    #[tokio::test]
+    async fn test_drift_needs_enough_history() {
+        let (plugin, id) = plugin_with_history(&[0.9; 24]).await;
+        
+        assert_eq!(plugin.detect_drift(id).await.unwrap(), None);
+        assert!(plugin.detect_drift(Uuid::new_v4()).await.is_err());
+    }
+
+    #[test]
+    fn test_uptime_ratio_over_scripted_transitions() {
+        use crate::test_support::sample_model;
+        use crate::ModelStatus;
+        use chrono::Duration;
+        
+        let mut model = sample_model("sla", 1.0, ModelStatus::Unknown);
+        let start = model.status_since;
+        let at = |minutes| start + Duration::minutes(minutes);
+        
+        // 10m unknown, 30m healthy, 10m degraded, 20m critical, then healthy again
+        model.set_status(ModelStatus::Healthy, at(10));
+        model.set_status(ModelStatus::Degraded, at(40));
+        model.set_status(ModelStatus::Critical, at(50));
+        model.set_status(ModelStatus::Healthy, at(70));
+        
+        assert_eq!(model.time_in_status(&ModelStatus::Unknown, at(90)), std::time::Duration::from_secs(600));
+        assert_eq!(model.time_in_status(&ModelStatus::Critical, at(90)), std::time::Duration::from_secs(1200));
+        // 50m healthy out of 80m monitored
+        assert!((model.uptime_ratio(at(90)) - 0.625).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_uptime_ratio_without_transitions() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("fresh".to_string(), ModelType::LLM).await.unwrap();
+        assert_eq!(plugin.uptime_ratio(id).await.unwrap(), 1.0);
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        assert_eq!(plugin.uptime_ratio(id).await.unwrap(), 0.0);
+        assert!(matches!(plugin.uptime_ratio(Uuid::new_v4()).await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_check_anomaly_flags_outlier() {
+        // A near-flat series around 0.9 followed by a sudden 0.4
+        let mut scores: Vec<f64> = (0..15).map(|i| if i % 2 == 0 { 0.91 } else { 0.89 }).collect();
+        scores.push(0.4);
+        let (plugin, id) = plugin_with_history(&scores).await;
+        
+        let report = plugin.check_anomaly(id).await.unwrap().unwrap();
+        assert!((report.mean - 0.9).abs() < 1e-3);
+        assert!((report.stddev - 0.01).abs() < 1e-3);
+        assert!(report.zscore < -40.0);
+        assert!(report.anomalous);
+        
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let report = plugin.check_anomaly(id).await.unwrap().unwrap();
+        assert!(!report.anomalous);
+    }
+
+    #[tokio::test]
+    async fn test_check_anomaly_edge_cases() {
+        let (plugin, id) = plugin_with_history(&[0.9; 9]).await;
+        assert!(plugin.check_anomaly(id).await.unwrap().is_none());
+        
+        // Perfectly flat history: an identical sample is normal, any change is anomalous
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let report = plugin.check_anomaly(id).await.unwrap().unwrap();
+        assert_eq!((report.stddev, report.zscore, report.anomalous), (0.0, 0.0, false));
+        
+        plugin.record_performance(id, 0.8).await.unwrap();
+        let report = plugin.check_anomaly(id).await.unwrap().unwrap();
+        assert_eq!(report.zscore, f64::NEG_INFINITY);
+        assert!(report.anomalous);
+        
+        assert!(matches!(plugin.check_anomaly(Uuid::new_v4()).await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_compare_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let baseline = plugin.add_model("baseline".to_string(), ModelType::LLM).await.unwrap();
+        let candidate = plugin.add_model("candidate".to_string(), ModelType::LLM).await.unwrap();
+        for score in [0.90, 0.88, 0.85, 0.82] {
+            plugin.record_performance(baseline, score).await.unwrap();
+        }
+        for score in [0.86, 0.89, 0.91, 0.93] {
+            plugin.record_performance(candidate, score).await.unwrap();
+        }
+        
+        let comparison = plugin.compare_models(baseline, candidate).await.unwrap();
+        assert_eq!(comparison.better, Some(candidate));
+        assert_eq!((comparison.a.score, comparison.b.score), (0.82, 0.93));
+        assert!((comparison.delta - 0.11).abs() < 1e-6);
+        assert_eq!(comparison.a_trend, Some(Trend::Declining));
+        assert_eq!(comparison.b_trend, Some(Trend::Improving));
+        
+        let missing = Uuid::new_v4();
+        assert!(matches!(plugin.compare_models(baseline, missing).await, Err(Error::ModelNotFound(id)) if id == missing));
+    }
+
+    #[tokio::test]
+    async fn test_compare_models_without_history() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), ModelType::LLM).await.unwrap();
+        let b = plugin.add_model("b".to_string(), ModelType::LLM).await.unwrap();
+        
+        let comparison = plugin.compare_models(a, b).await.unwrap();
+        assert_eq!(comparison.better, None);
+        assert_eq!((comparison.a_trend, comparison.b_trend), (None, None));
+    }
+
+    #[tokio::test]
+    async fn test_model_trend() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut ids = Vec::new();
+        for (name, scores) in [
+            ("rising", [0.70, 0.74, 0.78, 0.82, 0.86]),
+            ("flat", [0.90, 0.901, 0.899, 0.90, 0.901]),
+            ("falling", [0.95, 0.90, 0.85, 0.80, 0.75]),
+        ] {
+            let id = plugin.add_model(name.to_string(), ModelType::LLM).await.unwrap();
+            for score in scores {
+                plugin.record_performance(id, score).await.unwrap();
+            }
+            ids.push(id);
+        }
+        
+        assert_eq!(plugin.model_trend(ids[0]).await.unwrap(), Trend::Improving);
+        assert_eq!(plugin.model_trend(ids[1]).await.unwrap(), Trend::Stable);
+        assert_eq!(plugin.model_trend(ids[2]).await.unwrap(), Trend::Declining);
+        
+        let slope = plugin.model_trend_slope(ids[0]).await.unwrap().unwrap();
+        assert!((slope - 0.04).abs() < 1e-6);
+        let slope = plugin.model_trend_slope(ids[2]).await.unwrap().unwrap();
+        assert!((slope + 0.05).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_model_trend_epsilon_and_window() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("m".to_string(), ModelType::LLM).await.unwrap();
+        assert_eq!(plugin.model_trend(id).await.unwrap(), Trend::Unknown);
+        assert_eq!(plugin.model_trend_slope(id).await.unwrap(), None);
+        
+        plugin.record_performance(id, 0.9).await.unwrap();
+        assert_eq!(plugin.model_trend(id).await.unwrap(), Trend::Unknown);
+        
+        // Long decline followed by a short recovery
+        for score in [0.8, 0.7, 0.6, 0.62, 0.64] {
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        assert_eq!(plugin.model_trend(id).await.unwrap(), Trend::Declining);
+        
+        plugin.update_config(crate::PluginConfig {
+            trend_window: 3,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(plugin.model_trend(id).await.unwrap(), Trend::Improving);
+        
+        plugin.update_config(crate::PluginConfig {
+            trend_window: 3,
+            trend_epsilon: 0.05,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(plugin.model_trend(id).await.unwrap(), Trend::Stable);
+        
+        assert!(matches!(plugin.model_trend(Uuid::new_v4()).await, Err(Error::ModelNotFound(_))));
+    }
+}
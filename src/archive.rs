@@ -0,0 +1,122 @@
+//! Archive of removed models
+//!
+//! Removed models are kept, with their final status and history, so there is
+//! a record of what was monitored. The archive is capped at
+//! `archive_capacity` entries; the longest-removed model is evicted first.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{ModelPerformanceMonitoringPlugin, MonitoredModel};
+
+/// A removed model as it was at the moment of removal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ArchivedModel {
+    pub model: MonitoredModel,
+    pub removed_at: DateTime<Utc>,
+}
+
+/// Add `model` to `archive`, evicting the oldest entries beyond `capacity`
+pub(crate) fn archive_model(
+    archive: &mut HashMap<Uuid, Arc<ArchivedModel>>,
+    model: MonitoredModel,
+    removed_at: DateTime<Utc>,
+    capacity: usize,
+) {
+    if capacity == 0 {
+        return;
+    }
+    
+    archive.insert(model.id, Arc::new(ArchivedModel { model, removed_at }));
+    while archive.len() > capacity {
+        let oldest = archive
+            .values()
+            .min_by_key(|a| (a.removed_at, a.model.id))
+            .map(|a| a.model.id)
+            .expect("archive is non-empty");
+        archive.remove(&oldest);
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Archived models, most recently removed first
+    pub async fn list_archived(&self) -> Vec<ArchivedModel> {
+        let state = self.state.load();
+        let mut archived: Vec<ArchivedModel> = state.archived_models.values().map(Arc::as_ref).cloned().collect();
+        archived.sort_by_key(|a| std::cmp::Reverse(a.removed_at));
+        archived
+    }
+    
+    /// Drop every archived model and return how many were removed
+    pub async fn purge_archive(&self) -> usize {
+        let mut state = self.state.write().await;
+        let purged = state.archived_models.len();
+        state.archived_models.clear();
+        purged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_support, ModelStatus, PluginConfig};
+
+    #[tokio::test]
+    async fn test_removed_model_is_archived() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("retired".to_string(), "llm".to_string()).await.unwrap();
+        plugin.add_model("active".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.3).await.unwrap();
+        
+        plugin.remove_model(id).await.unwrap();
+        
+        let archived = plugin.list_archived().await;
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].model.id, id);
+        assert_eq!(archived[0].model.status, ModelStatus::Critical);
+        assert_eq!(archived[0].model.history.len(), 1);
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.system_metrics.total_models, 1);
+        // Only the never-checked model is left, and it has no score to average
+        assert_eq!(state.system_metrics.average_performance, 0.0);
+        drop(state);
+        
+        assert_eq!(plugin.purge_archive().await, 1);
+        assert!(plugin.list_archived().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_archive_evicts_oldest() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            archive_capacity: 2,
+            ..Default::default()
+        }).await.unwrap();
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            ids.push(plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap());
+        }
+        
+        for id in &ids {
+            plugin.remove_model(*id).await.unwrap();
+        }
+        
+        let archived: Vec<Uuid> = plugin.list_archived().await.iter().map(|a| a.model.id).collect();
+        assert_eq!(archived, vec![ids[2], ids[1]]);
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_archive() {
+        let mut archive = HashMap::new();
+        let model = test_support::sample_model("gone", 0.5, ModelStatus::Degraded);
+        
+        archive_model(&mut archive, model, Utc::now(), 0);
+        
+        assert!(archive.is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+//! Status-change audit log
+//!
+//! Every status transition is appended to `audit_log_path` as one JSON line,
+//! independently of the in-memory history, so it survives restarts and
+//! eviction. Lines are handed to a background writer over a channel, so
+//! file I/O never holds up the monitoring loop. The file is rotated once it
+//! would grow past `audit_log_max_bytes`, keeping `audit_log_keep` old files.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{Alert, ModelPerformanceMonitoringPlugin, ModelStatus, PluginConfig};
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AuditRecord {
+    pub timestamp: DateTime<Utc>,
+    pub model_id: Uuid,
+    pub from: ModelStatus,
+    pub to: ModelStatus,
+    /// Score that triggered the transition
+    pub score: f64,
+}
+
+impl From<&Alert> for AuditRecord {
+    fn from(alert: &Alert) -> Self {
+        Self {
+            timestamp: alert.timestamp,
+            model_id: alert.model_id,
+            from: alert.old_status.clone(),
+            to: alert.new_status.clone(),
+            score: alert.score,
+        }
+    }
+}
+
+/// Audit log settings in force when a record was queued
+#[derive(Debug, Clone)]
+pub(crate) struct AuditTarget {
+    path: PathBuf,
+    max_bytes: u64,
+    keep: usize,
+}
+
+pub(crate) type AuditSender = mpsc::UnboundedSender<(AuditTarget, AuditRecord)>;
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Queue `records` for the audit log, if one is configured
+    pub(crate) fn audit(&self, config: &PluginConfig, records: impl IntoIterator<Item = AuditRecord>) {
+        let Some(path) = &config.audit_log_path else {
+            return;
+        };
+        let target = AuditTarget {
+            path: path.clone(),
+            max_bytes: config.audit_log_max_bytes,
+            keep: config.audit_log_keep,
+        };
+        
+        let mut sender = self.audit_log.lock().unwrap();
+        let queue = match &*sender {
+            Some(queue) if !queue.is_closed() => queue,
+            _ => sender.insert(spawn_writer()),
+        };
+        for record in records {
+            let _ = queue.send((target.clone(), record));
+        }
+    }
+}
+
+/// Start the background writer, which appends queued records in batches
+/// and flushes after each batch
+fn spawn_writer() -> AuditSender {
+    let (tx, mut rx) = mpsc::unbounded_channel::<(AuditTarget, AuditRecord)>();
+    tokio::spawn(async move {
+        let mut file: Option<AuditFile> = None;
+        while let Some(first) = rx.recv().await {
+            let mut batch = vec![first];
+            while let Ok(next) = rx.try_recv() {
+                batch.push(next);
+            }
+            
+            for (target, record) in batch {
+                if let Err(e) = append(&mut file, &target, &record).await {
+                    error!("Failed to write audit log {}: {}", target.path.display(), e);
+                    file = None;
+                }
+            }
+            if let Some(open) = &mut file {
+                if let Err(e) = open.out.flush().await {
+                    error!("Failed to flush audit log {}: {}", open.path.display(), e);
+                }
+            }
+        }
+    });
+    tx
+}
+
+/// The audit log currently being appended to
+struct AuditFile {
+    path: PathBuf,
+    out: BufWriter<File>,
+    size: u64,
+}
+
+impl AuditFile {
+    async fn open(path: &Path) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path).await?;
+        let size = file.metadata().await?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            out: BufWriter::new(file),
+            size,
+        })
+    }
+}
+
+/// Append one record, rotating first if it would take the file past its limit
+async fn append(file: &mut Option<AuditFile>, target: &AuditTarget, record: &AuditRecord) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    
+    let mut current = match file.take() {
+        Some(current) if current.path == target.path => current,
+        Some(mut other) => {
+            other.out.flush().await?;
+            AuditFile::open(&target.path).await?
+        }
+        None => AuditFile::open(&target.path).await?,
+    };
+    if current.size > 0 && current.size + line.len() as u64 > target.max_bytes {
+        current.out.flush().await?;
+        drop(current);
+        rotate(&target.path, target.keep).await?;
+        current = AuditFile::open(&target.path).await?;
+    }
+    
+    let current = file.insert(current);
+    current.out.write_all(&line).await?;
+    current.size += line.len() as u64;
+    Ok(())
+}
+
+/// Shift `<path>.N` to `<path>.N+1`, dropping the oldest beyond `keep`, and
+/// move `path` itself to `<path>.1`
+async fn rotate(path: &Path, keep: usize) -> std::io::Result<()> {
+    if keep == 0 {
+        return tokio::fs::remove_file(path).await;
+    }
+    
+    for index in (1..keep).rev() {
+        match tokio::fs::rename(rotated(path, index), rotated(path, index + 1)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+    }
+    tokio::fs::rename(path, rotated(path, 1)).await
+}
+
+fn rotated(path: &Path, index: usize) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn scratch_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("adios-mpm-audit-{}", Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        dir
+    }
+
+    /// Poll until `path` holds `count` records and return them
+    async fn wait_for_records(path: &Path, count: usize) -> Vec<AuditRecord> {
+        for _ in 0..200 {
+            let text = std::fs::read_to_string(path).unwrap_or_default();
+            if text.lines().count() >= count {
+                return text.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("audit log never reached {} records", count);
+    }
+
+    #[tokio::test]
+    async fn test_transitions_are_audited() {
+        let dir = scratch_dir();
+        let path = dir.join("audit.jsonl");
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            audit_log_path: Some(path.clone()),
+            ..Default::default()
+        }).await.unwrap();
+        let id = plugin.add_model("audited".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.9).await.unwrap();
+        plugin.record_performance(id, 0.91).await.unwrap();
+        plugin.record_performance(id, 0.2).await.unwrap();
+        plugin.reset_model(id).await.unwrap();
+        
+        let records = wait_for_records(&path, 3).await;
+        let steps: Vec<_> = records.iter().map(|r| (r.from.clone(), r.to.clone())).collect();
+        assert_eq!(steps, vec![
+            (ModelStatus::Unknown, ModelStatus::Healthy),
+            (ModelStatus::Healthy, ModelStatus::Critical),
+            (ModelStatus::Critical, ModelStatus::Unknown),
+        ]);
+        assert!(records.iter().all(|r| r.model_id == id));
+        assert_eq!(records[0].score, 0.9);
+        assert_eq!(records[1].score, 0.2);
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_rotates_past_size_limit() {
+        let dir = scratch_dir();
+        let path = dir.join("audit.jsonl");
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            audit_log_path: Some(path.clone()),
+            audit_log_max_bytes: 400,
+            audit_log_keep: 2,
+            ..Default::default()
+        }).await.unwrap();
+        let id = plugin.add_model("flapping".to_string(), "llm".to_string()).await.unwrap();
+        
+        for _ in 0..10 {
+            plugin.record_performance(id, 0.9).await.unwrap();
+            plugin.record_performance(id, 0.2).await.unwrap();
+        }
+        
+        // The last transition always lands in the live file
+        for _ in 0..200 {
+            let text = std::fs::read_to_string(&path).unwrap_or_default();
+            if text.lines().last().is_some_and(|line| line.contains(r#""to":"Critical""#)) && rotated(&path, 2).exists() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        
+        assert!(rotated(&path, 1).exists());
+        assert!(rotated(&path, 2).exists());
+        assert!(!rotated(&path, 3).exists());
+        for file in [path.clone(), rotated(&path, 1), rotated(&path, 2)] {
+            let text = std::fs::read_to_string(&file).unwrap();
+            assert!(text.len() <= 400, "{} is {} bytes", file.display(), text.len());
+            for line in text.lines() {
+                serde_json::from_str::<AuditRecord>(line).unwrap();
+            }
+        }
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
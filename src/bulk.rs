@@ -0,0 +1,171 @@
+//! Bulk onboarding
+//!
+//! `bulk_add_models` registers a whole batch of models at once, all or
+//! nothing: every spec is checked against the others and the models already
+//! monitored before any of them is inserted, so a bad spec can't leave half
+//! a fleet onboarded.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{recompute_metrics, Error, ModelPerformanceMonitoringPlugin, ModelType, MonitoredModel, StateEvent};
+
+/// One model to add with `bulk_add_models`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModelSpec {
+    pub name: String,
+    pub model_type: ModelType,
+    /// Fleet to put the model in, if any
+    #[serde(default)]
+    pub fleet: Option<String>,
+}
+
+impl ModelSpec {
+    pub fn new(name: impl Into<String>, model_type: impl Into<ModelType>) -> Self {
+        Self {
+            name: name.into(),
+            model_type: model_type.into(),
+            fleet: None,
+        }
+    }
+    
+    pub fn with_fleet(mut self, fleet: impl Into<String>) -> Self {
+        self.fleet = Some(fleet.into());
+        self
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Register every model in `specs` and return their ids in order.
+    ///
+    /// Names must be non-empty and unique, both within the batch and against
+    /// the models already monitored, fleets must be non-empty, and the whole
+    /// batch must fit the tier's capacity. If any spec fails, nothing is
+    /// added and the error is an `InvalidSpec` naming the first offender.
+    pub async fn bulk_add_models(&self, specs: Vec<ModelSpec>) -> Result<Vec<Uuid>, Error> {
+        let mut state = self.state.write().await;
+        let existing: HashSet<&str> = state.monitored_models.values().map(|m| m.name.as_str()).collect();
+        let mut batch = HashSet::with_capacity(specs.len());
+        let capacity = state.config.tier.max_models().map(|limit| limit.saturating_sub(state.live_models()));
+        for (index, spec) in specs.iter().enumerate() {
+            let rejected = |source| Error::InvalidSpec {
+                index,
+                name: spec.name.clone(),
+                source: Box::new(source),
+            };
+            
+            if spec.name.trim().is_empty() {
+                return Err(rejected(Error::EmptyName));
+            }
+            if spec.fleet.as_deref().is_some_and(|fleet| fleet.trim().is_empty()) {
+                return Err(rejected(Error::EmptyFleet));
+            }
+            if existing.contains(spec.name.as_str()) || !batch.insert(spec.name.as_str()) {
+                return Err(rejected(Error::DuplicateName(spec.name.clone())));
+            }
+            if capacity.is_some_and(|free| index >= free) {
+                let tier = state.config.tier;
+                let limit = tier.max_models().unwrap_or_default();
+                return Err(rejected(Error::CapacityExceeded { tier, limit }));
+            }
+        }
+        drop(existing);
+        
+        let now = self.now();
+        let mut ids = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let model = MonitoredModel {
+                fleet: spec.fleet.map(|fleet| fleet.trim().to_string()),
+                ..MonitoredModel::new(spec.name, spec.model_type, now)
+            };
+            ids.push(model.id);
+            state.monitored_models.insert(model.id, Arc::new(model));
+        }
+        recompute_metrics(&mut state);
+        drop(state);
+        
+        info!("Monitoring {} models added in bulk", ids.len());
+        self.config_changed.notify_waiters();
+        for &id in &ids {
+            self.emit(StateEvent::ModelAdded(id));
+        }
+        self.emit(StateEvent::MetricsUpdated);
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PluginConfig, PricingTierName};
+
+    #[tokio::test]
+    async fn test_bulk_add_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let specs = vec![
+            ModelSpec::new("ranker", ModelType::LLM).with_fleet("search"),
+            ModelSpec::new("retriever", ModelType::Embedding).with_fleet("search"),
+            ModelSpec::new("fraud", ModelType::Classification),
+        ];
+        
+        let ids = plugin.bulk_add_models(specs).await.unwrap();
+        
+        assert_eq!(ids.len(), 3);
+        let ranker = plugin.get_model(ids[0]).await.unwrap();
+        assert_eq!(ranker.name, "ranker");
+        assert_eq!(ranker.fleet.as_deref(), Some("search"));
+        assert_eq!(plugin.get_model(ids[2]).await.unwrap().fleet, None);
+        assert_eq!(plugin.fleet_summary("search").await.total_models, 2);
+        assert_eq!(plugin.state.read().await.system_metrics.total_models, 3);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_in_batch_rejects_whole_batch() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let specs = vec![
+            ModelSpec::new("ranker", ModelType::LLM),
+            ModelSpec::new("retriever", ModelType::Embedding),
+            ModelSpec::new("ranker", ModelType::LLM),
+        ];
+        
+        let err = plugin.bulk_add_models(specs).await.unwrap_err();
+        
+        match err {
+            Error::InvalidSpec { index, name, source } => {
+                assert_eq!(index, 2);
+                assert_eq!(name, "ranker");
+                assert!(matches!(*source, Error::DuplicateName(_)));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(plugin.list_models(None).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_add_checks_existing_models_and_capacity() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.add_model("ranker".to_string(), ModelType::LLM).await.unwrap();
+        
+        let err = plugin.bulk_add_models(vec![
+            ModelSpec::new("fraud", ModelType::Classification),
+            ModelSpec::new("ranker", ModelType::LLM),
+        ]).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidSpec { index: 1, .. }));
+        let err = plugin.bulk_add_models(vec![ModelSpec::new("fraud", ModelType::Classification).with_fleet(" ")]).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidSpec { source, .. } if matches!(*source, Error::EmptyFleet)));
+        
+        plugin.update_config(PluginConfig {
+            tier: PricingTierName::Starter,
+            ..Default::default()
+        }).await.unwrap();
+        let limit = PricingTierName::Starter.max_models().unwrap();
+        let specs = (0..limit).map(|i| ModelSpec::new(format!("model-{}", i), ModelType::LLM)).collect();
+        let err = plugin.bulk_add_models(specs).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidSpec { index, .. } if index == limit - 1));
+        assert_eq!(plugin.list_models(None).await.len(), 1);
+    }
+}
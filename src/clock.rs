@@ -0,0 +1,112 @@
+//! Wall-clock time source
+//!
+//! Every timestamp the plugin records or compares against (last checks,
+//! staleness, uptime, cooldowns, warmup, maintenance windows) is read
+//! through a `Clock`, so tests can swap in a `MockClock` and step time
+//! forward deterministically instead of sleeping.
+
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex};
+
+use crate::ModelPerformanceMonitoringPlugin;
+
+/// Source of the current time
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, used unless another is set
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to. Clones share the same time, so a
+/// test can keep one handle and give another to the plugin.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    /// A clock stopped at `start`
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+    
+    /// Move the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+    }
+    
+    /// Jump the clock to `to`
+    pub fn set(&self, to: DateTime<Utc>) {
+        *self.now.lock().unwrap() = to;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Replace the clock every time read goes through
+    pub fn set_clock<C>(&self, clock: C)
+    where
+        C: Clock + 'static,
+    {
+        *self.clock.write().unwrap() = Arc::new(clock);
+    }
+    
+    /// Current time according to the configured clock
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        self.clock.read().unwrap().now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+
+    #[test]
+    fn test_mock_clock_moves_only_when_told() {
+        let start = Utc::now();
+        let clock = MockClock::new(start);
+        let handle = clock.clone();
+        
+        handle.advance(Duration::minutes(5));
+        assert_eq!(clock.now(), start + Duration::minutes(5));
+        handle.set(start);
+        assert_eq!(clock.now(), start);
+    }
+
+    #[tokio::test]
+    async fn test_model_becomes_stale_as_mock_clock_advances() {
+        let clock = MockClock::new(Utc::now());
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.set_clock(clock.clone());
+        let id = plugin.add_model("idle".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let max_age = std::time::Duration::from_secs(10 * 60);
+        
+        clock.advance(Duration::minutes(9));
+        assert!(plugin.stale_models(max_age).await.is_empty());
+        
+        clock.advance(Duration::minutes(2));
+        assert_eq!(plugin.stale_models(max_age).await, vec![id]);
+        
+        // A fresh score is stamped with the mock time and clears staleness
+        plugin.record_performance(id, 0.9).await.unwrap();
+        assert_eq!(plugin.get_model(id).await.unwrap().last_check, clock.now());
+        assert!(plugin.stale_models(max_age).await.is_empty());
+    }
+}
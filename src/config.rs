@@ -0,0 +1,841 @@
+//! Plugin configuration
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::{
+    AlertFormat, AlertSecret, BackoffPolicy, Severity, Error, MaintenanceWindow, MetricLabels, ModelPerformanceMonitoringPlugin, ModelStatus,
+    PricingTierName,
+};
+
+/// Environment variable naming the config file to load
+pub const CONFIG_ENV_VAR: &str = "ADIOS_CONFIG";
+
+/// Config file used when `ADIOS_CONFIG` is unset, relative to the working directory
+pub const DEFAULT_CONFIG_PATH: &str = "adios-monitoring.toml";
+
+/// Environment variables overriding config fields, layered over the file or defaults
+pub const CHECK_INTERVAL_ENV_VAR: &str = "ADIOS_CHECK_INTERVAL_MINUTES";
+pub const PERFORMANCE_THRESHOLD_ENV_VAR: &str = "ADIOS_PERFORMANCE_THRESHOLD";
+pub const AUTO_REMEDIATION_ENV_VAR: &str = "ADIOS_AUTO_REMEDIATION";
+pub const ALERT_ENABLED_ENV_VAR: &str = "ADIOS_ALERT_ENABLED";
+
+/// Return early with `Error::InvalidConfig`, formatting like `format!`
+macro_rules! invalid {
+    ($($arg:tt)*) => {
+        return Err(Error::InvalidConfig(format!($($arg)*)))
+    };
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct PluginConfig {
+    pub check_interval_minutes: u32,
+    
+    /// Largest random delay added to each scheduled check, to keep checks of
+    /// many models from landing on the same instant. 0 disables jitter.
+    pub check_jitter_seconds: u32,
+    
+    /// Seed for the jitter RNG, read when the monitoring loop starts; the same
+    /// seed gives the same jitter sequence. Random when unset.
+    pub check_jitter_seed: Option<u64>,
+    
+    pub performance_threshold: f64,
+    pub auto_remediation: bool,
+    
+    /// Log and record what auto-remediation would do without running the handler
+    pub dry_run: bool,
+    pub alert_enabled: bool,
+    
+    /// Maximum number of score samples kept per model
+    pub history_capacity: usize,
+    
+    /// Number of recent samples averaged before comparing against
+    /// `performance_threshold`. A window of 1 uses the latest score as-is;
+    /// larger windows damp single noisy readings at the cost of reacting
+    /// more slowly to genuine changes.
+    pub smoothing_window: usize,
+    
+    /// Minutes after a model is added during which it can go `Degraded`
+    /// but not `Critical` and sends no alerts, while its caches fill.
+    /// 0 disables.
+    pub warmup_minutes: u32,
+    
+    /// Consecutive healthy readings a `Degraded` or `Critical` model needs
+    /// before it is marked `Healthy` again, so a flapping model doesn't
+    /// recover on one good score. 1 recovers immediately.
+    pub recovery_confirmations: u32,
+    
+    /// Missed check cycles in a row after which a model is marked
+    /// `Offline`: cycles where a pull fetch failed, or where a push-based
+    /// model reported nothing for longer than its check interval. 0 disables.
+    pub offline_after_missed_checks: u32,
+    
+    /// URL that receives a JSON POST when a model becomes degraded or critical
+    pub alert_webhook: Option<String>,
+    
+    /// Per-severity webhook URLs, e.g. `Critical` to a paging channel;
+    /// severities without an entry go to `alert_webhook`
+    pub alert_routes: HashMap<Severity, String>,
+    
+    /// Payload shape used for webhook alerts
+    pub alert_format: AlertFormat,
+    
+    /// Key for the HMAC-SHA256 signature sent with every webhook alert.
+    /// Read from the config file only: never logged or written to saved state.
+    #[serde(skip_serializing)]
+    pub alert_signing_secret: Option<AlertSecret>,
+    
+    /// Delay before the first webhook delivery retry, doubled for each
+    /// further retry, with jitter
+    pub alert_retry_base_ms: u64,
+    
+    /// Longest delay between webhook delivery retries
+    pub alert_retry_max_ms: u64,
+    
+    /// Delivery attempts per alert before it is logged and counted as failed
+    pub alert_max_attempts: u32,
+    
+    /// Minimum time between two alerts for the same model entering the same
+    /// status. Recoveries to `Healthy` are never held back. 0 disables.
+    pub alert_cooldown_minutes: u32,
+    
+    /// Most alerts sent per minute across all models, to protect webhook
+    /// quotas; further alerts are dropped and counted. 0 disables the cap.
+    pub alert_rate_limit_per_minute: u32,
+    
+    /// Number of models entering the same status in one check cycle above
+    /// which a single aggregate alert is sent instead of one per model.
+    /// 0 disables batching.
+    pub alert_batch_threshold: usize,
+    
+    /// Daily UTC time ranges during which no webhook alerts are sent
+    pub maintenance_windows: Vec<MaintenanceWindow>,
+    
+    /// Relative drop (0.1 = 10%) of the recent mean below the baseline mean
+    /// at which a model is reported as drifting
+    pub drift_threshold: f64,
+    
+    /// Number of most recent samples compared against the baseline
+    pub drift_recent_window: usize,
+    
+    /// Number of samples immediately before the recent window used as the
+    /// baseline. Drift is only reported once a model has both windows' worth
+    /// of history, so `history_capacity` must be at least the sum of the two.
+    pub drift_baseline_window: usize,
+    
+    /// Most notes kept per model; the oldest are dropped first
+    pub notes_capacity: usize,
+    
+    /// Number of most recent samples the score trend is fitted over
+    pub trend_window: usize,
+    
+    /// Slope, in score per sample, within which a trend counts as `Stable`
+    pub trend_epsilon: f64,
+    
+    /// Where state is saved on shutdown; nothing is persisted when unset
+    pub state_path: Option<PathBuf>,
+    
+    /// Licensed tier, which caps the number of monitored models
+    pub tier: PricingTierName,
+    
+    /// |z-score| of the latest sample against earlier history above which
+    /// it is reported as anomalous
+    pub anomaly_zscore: f64,
+    
+    /// Minimum history length before anomalies are reported
+    pub anomaly_min_samples: usize,
+    
+    /// Longest metadata key accepted by `set_metadata`, in bytes
+    pub max_metadata_key_len: usize,
+    
+    /// Longest metadata value accepted by `set_metadata`, in bytes
+    pub max_metadata_value_len: usize,
+    
+    /// Weight in (0, 1] of the newest sample when exponentially smoothing
+    /// `performance_score`: `score = alpha * new + (1 - alpha) * previous`.
+    /// Smaller values react more slowly; 1.0 keeps only the newest sample.
+    /// Disabled (`None`) by default. When set, status is derived from the
+    /// smoothed score and `smoothing_window` is not used.
+    pub decay_alpha: Option<f64>,
+    
+    /// How long samples are kept in the time-series store
+    pub series_retention_hours: u32,
+    
+    /// Labels per-model series are exported under by `prometheus_metrics`,
+    /// which bounds their cardinality
+    pub metric_labels: MetricLabels,
+    
+    /// Prometheus Pushgateway base URL the monitoring loop pushes metrics
+    /// to, for deployments that cannot be scraped
+    pub pushgateway_url: Option<String>,
+    
+    /// `job` label the metrics are pushed under
+    pub pushgateway_job: String,
+    
+    /// Time between pushes, independent of `check_interval_minutes`
+    pub pushgateway_interval_seconds: u64,
+    
+    /// `[index, total]` when several instances split the fleet: this
+    /// instance only checks models whose id hashes into shard `index` of
+    /// `total`. Unset checks every model.
+    pub shard: Option<(u32, u32)>,
+    
+    /// Times a panicked monitoring loop is restarted, with exponential
+    /// backoff, before monitoring is given up
+    pub monitor_max_restarts: u32,
+    
+    /// Longest a remediation handler or metrics source fetch may run before
+    /// it is abandoned and recorded as a failure
+    pub operation_timeout_seconds: u64,
+    
+    /// How `load_state` treats a model with a `last_check` or `status_since`
+    /// in the future, or a `created_at` after its `last_check`: when false
+    /// the timestamps are clamped with a warning, when true loading fails
+    pub strict_state_validation: bool,
+    
+    /// Hours a soft-deleted model can still be restored before it is
+    /// purged and archived
+    pub soft_delete_ttl_hours: u32,
+    
+    /// Maximum number of removed models kept in the archive; the longest
+    /// removed are evicted first. 0 disables archiving.
+    pub archive_capacity: usize,
+    
+    /// JSON Lines file every status transition is appended to, for
+    /// post-incident review. Unset disables the audit log.
+    pub audit_log_path: Option<PathBuf>,
+    
+    /// Size in bytes past which the audit log is rotated
+    pub audit_log_max_bytes: u64,
+    
+    /// Rotated audit logs kept (`<path>.1` being the newest); older ones
+    /// are deleted
+    pub audit_log_keep: usize,
+}
+
+impl Default for PluginConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_minutes: 5,
+            check_jitter_seconds: 0,
+            check_jitter_seed: None,
+            performance_threshold: 0.85,
+            auto_remediation: true,
+            dry_run: false,
+            alert_enabled: true,
+            history_capacity: 100,
+            smoothing_window: 1,
+            warmup_minutes: 0,
+            recovery_confirmations: 1,
+            offline_after_missed_checks: 3,
+            alert_webhook: None,
+            alert_routes: HashMap::new(),
+            alert_format: AlertFormat::Raw,
+            alert_signing_secret: None,
+            alert_retry_base_ms: 500,
+            alert_retry_max_ms: 30_000,
+            alert_max_attempts: 3,
+            alert_cooldown_minutes: 15,
+            alert_batch_threshold: 10,
+            maintenance_windows: Vec::new(),
+            alert_rate_limit_per_minute: 60,
+            drift_threshold: 0.1,
+            drift_recent_window: 5,
+            drift_baseline_window: 20,
+            notes_capacity: 100,
+            trend_window: 20,
+            trend_epsilon: 0.005,
+            state_path: None,
+            tier: PricingTierName::default(),
+            anomaly_zscore: 3.0,
+            anomaly_min_samples: 10,
+            max_metadata_key_len: 128,
+            max_metadata_value_len: 1024,
+            decay_alpha: None,
+            series_retention_hours: 24,
+            metric_labels: MetricLabels::default(),
+            pushgateway_url: None,
+            pushgateway_job: "adios_model_monitoring".to_string(),
+            pushgateway_interval_seconds: 60,
+            shard: None,
+            monitor_max_restarts: 5,
+            operation_timeout_seconds: 30,
+            strict_state_validation: false,
+            soft_delete_ttl_hours: 72,
+            archive_capacity: 100,
+            audit_log_path: None,
+            audit_log_max_bytes: 10 * 1024 * 1024,
+            audit_log_keep: 5,
+        }
+    }
+}
+
+impl PluginConfig {
+    /// Load a TOML config file over the defaults.
+    ///
+    /// Missing fields keep their default values and unknown fields are
+    /// ignored. The result is validated before it is returned.
+    pub fn from_toml_file(path: &Path) -> Result<PluginConfig, Error> {
+        let text = std::fs::read_to_string(path).map_err(|source| Error::ConfigFile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let config: PluginConfig = toml::from_str(&text).map_err(|source| Error::ConfigParse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Config for a new plugin: the file named by `ADIOS_CONFIG` if set,
+    /// otherwise `DEFAULT_CONFIG_PATH` if it exists, otherwise the defaults,
+    /// with any `ADIOS_*` field overrides from the environment applied
+    pub fn load() -> Result<PluginConfig, Error> {
+        let explicit = std::env::var_os(CONFIG_ENV_VAR).map(PathBuf::from);
+        let mut config = Self::load_from(explicit.as_deref(), Path::new(DEFAULT_CONFIG_PATH))?;
+        config.apply_env_overrides(|name| std::env::var(name).ok())?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Override fields from the variables `lookup` returns, failing with
+    /// `InvalidEnvVar` on the first value that does not parse or is out of range
+    pub fn apply_env_overrides(&mut self, lookup: impl Fn(&str) -> Option<String>) -> Result<(), Error> {
+        if let Some(minutes) = env_value(&lookup, CHECK_INTERVAL_ENV_VAR, |value| match value.parse::<u32>() {
+            Ok(0) => Err("must be greater than 0".to_string()),
+            Ok(minutes) => Ok(minutes),
+            Err(e) => Err(e.to_string()),
+        })? {
+            self.check_interval_minutes = minutes;
+        }
+        if let Some(threshold) = env_value(&lookup, PERFORMANCE_THRESHOLD_ENV_VAR, |value| match value.parse::<f64>() {
+            Ok(threshold) if (0.0..=1.0).contains(&threshold) => Ok(threshold),
+            Ok(_) => Err("must be between 0.0 and 1.0".to_string()),
+            Err(e) => Err(e.to_string()),
+        })? {
+            self.performance_threshold = threshold;
+        }
+        if let Some(enabled) = env_value(&lookup, AUTO_REMEDIATION_ENV_VAR, parse_bool)? {
+            self.auto_remediation = enabled;
+        }
+        if let Some(enabled) = env_value(&lookup, ALERT_ENABLED_ENV_VAR, parse_bool)? {
+            self.alert_enabled = enabled;
+        }
+        Ok(())
+    }
+    
+    fn load_from(explicit: Option<&Path>, fallback: &Path) -> Result<PluginConfig, Error> {
+        match explicit {
+            // A path the operator asked for must exist
+            Some(path) => Self::from_toml_file(path),
+            None if fallback.exists() => Self::from_toml_file(fallback),
+            None => Ok(PluginConfig::default()),
+        }
+    }
+    
+    /// Reject configurations the monitoring loop cannot run with
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.check_interval_minutes == 0 {
+            invalid!("check_interval_minutes must be greater than 0");
+        }
+        if !(0.0..=1.0).contains(&self.performance_threshold) {
+            invalid!(
+                "performance_threshold must be between 0.0 and 1.0, got {}",
+                self.performance_threshold
+            );
+        }
+        if self.history_capacity == 0 {
+            invalid!("history_capacity must be greater than 0");
+        }
+        if self.smoothing_window == 0 || self.smoothing_window > self.history_capacity {
+            invalid!(
+                "smoothing_window must be between 1 and history_capacity ({}), got {}",
+                self.history_capacity,
+                self.smoothing_window
+            );
+        }
+        if self.recovery_confirmations == 0 {
+            invalid!("recovery_confirmations must be at least 1");
+        }
+        if !(0.0..=1.0).contains(&self.drift_threshold) {
+            invalid!("drift_threshold must be between 0.0 and 1.0, got {}", self.drift_threshold);
+        }
+        if self.drift_recent_window == 0 || self.drift_baseline_window == 0 {
+            invalid!("drift_recent_window and drift_baseline_window must be greater than 0");
+        }
+        if self.history_capacity < self.drift_recent_window + self.drift_baseline_window {
+            invalid!(
+                "history_capacity ({}) must be at least drift_recent_window + drift_baseline_window ({})",
+                self.history_capacity,
+                self.drift_recent_window + self.drift_baseline_window
+            );
+        }
+        if self.notes_capacity == 0 {
+            invalid!("notes_capacity must be greater than 0");
+        }
+        if self.trend_window < 2 {
+            invalid!("trend_window must be at least 2, got {}", self.trend_window);
+        }
+        if !(self.trend_epsilon >= 0.0 && self.trend_epsilon.is_finite()) {
+            invalid!("trend_epsilon must be a finite, non-negative number, got {}", self.trend_epsilon);
+        }
+        if self.anomaly_zscore.is_nan() || self.anomaly_zscore <= 0.0 {
+            invalid!("anomaly_zscore must be greater than 0, got {}", self.anomaly_zscore);
+        }
+        if self.anomaly_min_samples < 3 {
+            invalid!("anomaly_min_samples must be at least 3, got {}", self.anomaly_min_samples);
+        }
+        if self.series_retention_hours == 0 {
+            invalid!("series_retention_hours must be greater than 0");
+        }
+        if let Some(window) = self.maintenance_windows.iter().find(|w| w.start == w.end) {
+            invalid!("maintenance window must not start and end at the same time, got {}", window.start);
+        }
+        if let Some((index, total)) = self.shard {
+            if index >= total {
+                invalid!("shard index must be less than the shard total, got [{}, {}]", index, total);
+            }
+        }
+        if self.pushgateway_job.is_empty() || self.pushgateway_job.contains('/') {
+            invalid!("pushgateway_job must be non-empty and must not contain '/'");
+        }
+        if self.pushgateway_interval_seconds == 0 {
+            invalid!("pushgateway_interval_seconds must be greater than 0");
+        }
+        if self.alert_max_attempts == 0 {
+            invalid!("alert_max_attempts must be at least 1");
+        }
+        if self.alert_retry_base_ms == 0 || self.alert_retry_max_ms < self.alert_retry_base_ms {
+            invalid!(
+                "alert retry delays must satisfy 0 < alert_retry_base_ms <= alert_retry_max_ms, got {} and {}",
+                self.alert_retry_base_ms,
+                self.alert_retry_max_ms
+            );
+        }
+        if self.operation_timeout_seconds == 0 {
+            invalid!("operation_timeout_seconds must be greater than 0");
+        }
+        if self.audit_log_max_bytes == 0 {
+            invalid!("audit_log_max_bytes must be greater than 0");
+        }
+        if let Some(alpha) = self.decay_alpha {
+            if !(alpha > 0.0 && alpha <= 1.0) {
+                invalid!("decay_alpha must be in the range (0, 1], got {}", alpha);
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// `series_retention_hours` as a duration
+    pub fn series_retention(&self) -> chrono::Duration {
+        chrono::Duration::hours(i64::from(self.series_retention_hours))
+    }
+    
+    /// Retry schedule for webhook deliveries
+    pub fn alert_backoff(&self) -> BackoffPolicy {
+        BackoffPolicy {
+            base: std::time::Duration::from_millis(self.alert_retry_base_ms),
+            max: std::time::Duration::from_millis(self.alert_retry_max_ms),
+            max_attempts: self.alert_max_attempts,
+        }
+    }
+    
+    /// `operation_timeout_seconds` as a duration
+    pub fn operation_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.operation_timeout_seconds)
+    }
+    
+    /// Derive a model status from a score relative to `performance_threshold`
+    pub fn status_for_score(&self, score: f64) -> ModelStatus {
+        ModelStatus::from_score(score, self.performance_threshold)
+    }
+    
+    /// Fields that differ between `self` and `new`, sorted by field name.
+    ///
+    /// Compares the serialized form, so every field is covered without being
+    /// listed here. The signing secret is never serialized, so a change to it
+    /// is reported with redacted values.
+    pub fn diff(&self, new: &PluginConfig) -> Vec<ConfigChange> {
+        let (Ok(Value::Object(old_fields)), Ok(Value::Object(mut new_fields))) =
+            (serde_json::to_value(self), serde_json::to_value(new))
+        else {
+            unreachable!("PluginConfig serializes to a JSON object");
+        };
+        
+        let mut changes: Vec<ConfigChange> = old_fields
+            .into_iter()
+            .filter_map(|(field, old)| {
+                let new = new_fields.remove(&field).unwrap_or(Value::Null);
+                (old != new).then_some(ConfigChange { field, old, new })
+            })
+            .collect();
+        
+        if self.alert_signing_secret != new.alert_signing_secret {
+            let redacted = |secret: &Option<AlertSecret>| match secret {
+                Some(_) => Value::from("<redacted>"),
+                None => Value::Null,
+            };
+            changes.push(ConfigChange {
+                field: "alert_signing_secret".to_string(),
+                old: redacted(&self.alert_signing_secret),
+                new: redacted(&new.alert_signing_secret),
+            });
+            changes.sort_by(|a, b| a.field.cmp(&b.field));
+        }
+        changes
+    }
+}
+
+/// One field that `PluginConfig::diff` found changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConfigChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// Parse variable `name`, if set, naming it in the error when `parse` fails
+fn env_value<T>(
+    lookup: &impl Fn(&str) -> Option<String>,
+    name: &'static str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Option<T>, Error> {
+    let Some(value) = lookup(name) else {
+        return Ok(None);
+    };
+    parse(value.trim())
+        .map(Some)
+        .map_err(|reason| Error::InvalidEnvVar { name, value, reason })
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Ok(true),
+        "false" | "0" | "no" | "off" => Ok(false),
+        _ => Err("expected true or false".to_string()),
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// The running config, read without locking the plugin state.
+    ///
+    /// For hot paths that need only the config. Code that must see the
+    /// config and the models as of the same moment should read both through
+    /// the state instead.
+    pub fn current_config(&self) -> Arc<PluginConfig> {
+        self.state.config()
+    }
+    
+    /// What `update_config(new)` would change, without applying it
+    pub async fn config_diff(&self, new: &PluginConfig) -> Vec<ConfigChange> {
+        self.current_config().diff(new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert!(PluginConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_interval() {
+        let config = PluginConfig {
+            check_interval_minutes: 0,
+            ..PluginConfig::default()
+        };
+        
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("check_interval_minutes"));
+    }
+
+    #[test]
+    fn test_validate_rejects_threshold_above_one() {
+        let config = PluginConfig {
+            performance_threshold: 2.0,
+            ..PluginConfig::default()
+        };
+        
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("performance_threshold"));
+    }
+
+    #[test]
+    fn test_validate_rejects_negative_threshold() {
+        let config = PluginConfig {
+            performance_threshold: -0.1,
+            ..PluginConfig::default()
+        };
+        
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_history_capacity() {
+        let config = PluginConfig {
+            history_capacity: 0,
+            ..PluginConfig::default()
+        };
+        
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("history_capacity"));
+    }
+
+    #[test]
+    fn test_validate_rejects_history_capacity_below_drift_windows() {
+        let config = PluginConfig {
+            history_capacity: 30,
+            drift_recent_window: 10,
+            drift_baseline_window: 21,
+            ..PluginConfig::default()
+        };
+        
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("drift_recent_window + drift_baseline_window"));
+        PluginConfig { drift_baseline_window: 20, ..config }.validate().unwrap();
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_smoothing_window() {
+        for window in [0, 101] {
+            let config = PluginConfig {
+                smoothing_window: window,
+                ..PluginConfig::default()
+            };
+            
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("smoothing_window"));
+        }
+    }
+
+    #[test]
+    fn test_validate_decay_alpha() {
+        for alpha in [0.0, -0.5, 1.5, f64::NAN] {
+            let config = PluginConfig {
+                decay_alpha: Some(alpha),
+                ..PluginConfig::default()
+            };
+            
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("decay_alpha"));
+        }
+        
+        let config = PluginConfig {
+            decay_alpha: Some(1.0),
+            ..PluginConfig::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_nan_threshold() {
+        let config = PluginConfig {
+            performance_threshold: f64::NAN,
+            ..PluginConfig::default()
+        };
+        
+        assert!(config.validate().is_err());
+    }
+
+    fn write_scratch(contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("adios-mpm-config-{}.toml", uuid::Uuid::new_v4()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_from_toml_file_partial_override() {
+        let path = write_scratch(
+            r#"
+            check_interval_minutes = 15
+            performance_threshold = 0.9
+            alert_format = "Slack"
+            state_path = "/var/lib/adios/state.json"
+            some_future_setting = "ignored"
+            "#,
+        );
+        
+        let config = PluginConfig::from_toml_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(config.check_interval_minutes, 15);
+        assert_eq!(config.performance_threshold, 0.9);
+        assert_eq!(config.alert_format, AlertFormat::Slack);
+        assert_eq!(config.state_path, Some(PathBuf::from("/var/lib/adios/state.json")));
+        // Everything else keeps its default
+        assert_eq!(config.history_capacity, PluginConfig::default().history_capacity);
+        assert!(config.auto_remediation);
+    }
+
+    #[test]
+    fn test_from_toml_file_validates() {
+        let path = write_scratch("check_interval_minutes = 0\n");
+        let err = PluginConfig::from_toml_file(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(err, Error::InvalidConfig(_)));
+        
+        let path = write_scratch("performance_threshold = \"high\"\n");
+        let err = PluginConfig::from_toml_file(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(err, Error::ConfigParse { .. }));
+    }
+
+    #[test]
+    fn test_load_from_falls_back_to_defaults() {
+        let missing = std::env::temp_dir().join(format!("adios-mpm-missing-{}.toml", uuid::Uuid::new_v4()));
+        
+        let config = PluginConfig::load_from(None, &missing).unwrap();
+        assert_eq!(config.check_interval_minutes, PluginConfig::default().check_interval_minutes);
+        assert!(matches!(PluginConfig::load_from(Some(&missing), &missing), Err(Error::ConfigFile { .. })));
+        
+        let path = write_scratch("tier = \"Starter\"\n");
+        let config = PluginConfig::load_from(None, &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(config.tier, PricingTierName::Starter);
+    }
+
+    #[test]
+    fn test_validate_anomaly_settings() {
+        for config in [
+            PluginConfig { anomaly_zscore: 0.0, ..PluginConfig::default() },
+            PluginConfig { anomaly_zscore: f64::NAN, ..PluginConfig::default() },
+            PluginConfig { anomaly_min_samples: 2, ..PluginConfig::default() },
+        ] {
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("anomaly_"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_config_diff() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let current = plugin.snapshot().await.config;
+        assert!(plugin.config_diff(&current).await.is_empty());
+        
+        let changes = plugin.config_diff(&PluginConfig {
+            performance_threshold: 0.9,
+            ..current.clone()
+        }).await;
+        assert_eq!(changes, vec![ConfigChange {
+            field: "performance_threshold".to_string(),
+            old: Value::from(current.performance_threshold),
+            new: Value::from(0.9),
+        }]);
+        
+        let changes = plugin.config_diff(&PluginConfig {
+            alert_signing_secret: Some(AlertSecret::new("s3cret")),
+            tier: PricingTierName::Starter,
+            ..current
+        }).await;
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["alert_signing_secret", "tier"]);
+        assert!(!format!("{:?}", changes).contains("s3cret"));
+        
+        // Previewing never applies anything
+        assert_eq!(plugin.snapshot().await.config.tier, PricingTierName::default());
+    }
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let mut config = PluginConfig::default();
+        config.apply_env_overrides(env(&[])).unwrap();
+        assert_eq!(config.check_interval_minutes, 5);
+        
+        config.apply_env_overrides(env(&[(CHECK_INTERVAL_ENV_VAR, "15")])).unwrap();
+        assert_eq!(config.check_interval_minutes, 15);
+        
+        config.apply_env_overrides(env(&[(PERFORMANCE_THRESHOLD_ENV_VAR, " 0.7 ")])).unwrap();
+        assert_eq!(config.performance_threshold, 0.7);
+        
+        config.apply_env_overrides(env(&[(AUTO_REMEDIATION_ENV_VAR, "false")])).unwrap();
+        assert!(!config.auto_remediation);
+        
+        config.apply_env_overrides(env(&[(ALERT_ENABLED_ENV_VAR, "0")])).unwrap();
+        assert!(!config.alert_enabled);
+        
+        // Earlier overrides are kept, later ones layer on top
+        assert_eq!(config.check_interval_minutes, 15);
+        config.apply_env_overrides(env(&[(ALERT_ENABLED_ENV_VAR, "TRUE")])).unwrap();
+        assert!(config.alert_enabled);
+    }
+
+    #[test]
+    fn test_invalid_env_override_names_variable() {
+        let mut config = PluginConfig::default();
+        
+        let err = config.apply_env_overrides(env(&[(CHECK_INTERVAL_ENV_VAR, "five")])).unwrap_err();
+        assert!(matches!(err, Error::InvalidEnvVar { name: CHECK_INTERVAL_ENV_VAR, .. }));
+        assert!(err.to_string().contains("ADIOS_CHECK_INTERVAL_MINUTES"));
+        
+        let err = config.apply_env_overrides(env(&[(PERFORMANCE_THRESHOLD_ENV_VAR, "1.5")])).unwrap_err();
+        assert!(err.to_string().contains("ADIOS_PERFORMANCE_THRESHOLD"));
+        assert!(err.to_string().contains("between 0.0 and 1.0"));
+        
+        let err = config.apply_env_overrides(env(&[(AUTO_REMEDIATION_ENV_VAR, "maybe")])).unwrap_err();
+        assert!(matches!(err, Error::InvalidEnvVar { name: AUTO_REMEDIATION_ENV_VAR, .. }));
+        
+        // Nothing was applied
+        assert_eq!(config.check_interval_minutes, 5);
+        assert_eq!(config.performance_threshold, 0.85);
+    }
+
+    #[tokio::test]
+    async fn test_current_config_follows_update_config() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let before = plugin.current_config();
+        
+        plugin.update_config(PluginConfig {
+            check_interval_minutes: 11,
+            ..Default::default()
+        }).await.unwrap();
+        
+        assert_eq!(before.check_interval_minutes, 5);
+        assert_eq!(plugin.current_config().check_interval_minutes, 11);
+        assert_eq!(plugin.snapshot().await.config.check_interval_minutes, 11);
+        
+        // A rejected config is never published
+        let _ = plugin.update_config(PluginConfig {
+            check_interval_minutes: 0,
+            ..Default::default()
+        }).await;
+        assert_eq!(plugin.current_config().check_interval_minutes, 11);
+    }
+
+    #[tokio::test]
+    async fn test_config_reads_do_not_wait_for_model_writes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("busy".to_string(), "llm".to_string()).await.unwrap();
+        
+        // A long write to the model map, holding the state lock throughout
+        let mut state = plugin.state.write().await;
+        state.model_mut(id).unwrap().performance_score = 0.5;
+        
+        let reader = plugin.clone();
+        let read = tokio::spawn(async move {
+            let threshold = reader.current_config().performance_threshold;
+            let allowed = reader.tier_allows(crate::Feature::BasicMonitoring);
+            let changes = reader.config_diff(&PluginConfig::default()).await;
+            (threshold, allowed, changes.is_empty())
+        });
+        let read = tokio::time::timeout(std::time::Duration::from_secs(1), read).await;
+        drop(state);
+        
+        assert_eq!(read.expect("config read blocked on the state lock").unwrap(), (0.85, true, true));
+    }
+}
@@ -0,0 +1,205 @@
+//! Model dependency graph
+//!
+//! A model can depend on upstream models, such as a feature pipeline. While
+//! any direct dependency is `Critical` or `Offline`, a model whose own
+//! scores are healthy is marked `Degraded` instead, and it returns to
+//! `Healthy` once the dependency recovers or is removed. Cycles are rejected
+//! when dependencies are set.
+
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    recompute_metrics, Alert, Error, ModelPerformanceMonitoringPlugin, ModelStatus, MonitoredModel, PluginConfig, Transition,
+};
+
+/// Whether any direct dependency of `id`, other than a soft-deleted one, is
+/// currently `Critical` or worse
+pub(crate) fn dependency_critical(models: &HashMap<Uuid, Arc<MonitoredModel>>, id: Uuid) -> bool {
+    models.get(&id).is_some_and(|model| {
+        model
+            .depends_on
+            .iter()
+            .any(|dep| models.get(dep).is_some_and(|m| m.status >= ModelStatus::Critical && m.deleted_at.is_none()))
+    })
+}
+
+/// Bring every model's cascaded status in line with its dependencies'
+/// current statuses, returning the resulting transitions.
+///
+/// Only `Critical` and `Offline` cascade and the cascade only ever produces
+/// `Degraded`, so a single pass over direct dependencies is enough.
+pub(crate) fn propagate_dependencies(
+    models: &mut HashMap<Uuid, Arc<MonitoredModel>>,
+    config: &PluginConfig,
+    now: DateTime<Utc>,
+) -> Vec<Transition> {
+    let affected: Vec<(Uuid, bool)> = models
+        .values()
+        .filter(|m| (!m.depends_on.is_empty() || m.dependency_degraded) && m.is_active())
+        .map(|m| (m.id, dependency_critical(models, m.id)))
+        .collect();
+    
+    let mut transitions = Vec::new();
+    for (id, upstream_critical) in affected {
+        let model = models.get_mut(&id).expect("collected from the same map");
+        let new_status = if upstream_critical && model.status == ModelStatus::Healthy {
+            ModelStatus::Degraded
+        } else if !upstream_critical && model.dependency_degraded {
+            ModelStatus::Healthy
+        } else {
+            continue;
+        };
+        
+        // Copied only once it is known to change, so snapshots keep sharing the rest
+        let model = Arc::make_mut(model);
+        model.dependency_degraded = upstream_critical;
+        let old_status = model.set_status(new_status, now);
+        transitions.push(Transition {
+            alert: Alert::new(model, old_status),
+            remediate: None,
+            in_warmup: model.in_warmup(config, now),
+        });
+    }
+    transitions
+}
+
+/// Whether `from` reaches `target` by following `depends_on` edges
+fn reaches(models: &HashMap<Uuid, Arc<MonitoredModel>>, from: Uuid, target: Uuid) -> bool {
+    let mut stack = vec![from];
+    let mut seen = HashSet::new();
+    while let Some(id) = stack.pop() {
+        if id == target {
+            return true;
+        }
+        if seen.insert(id) {
+            stack.extend(models.get(&id).into_iter().flat_map(|m| m.depends_on.iter().copied()));
+        }
+    }
+    false
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Replace the upstream models `id` depends on.
+    ///
+    /// Fails with `ModelNotFound` for an unknown model or dependency and with
+    /// `DependencyCycle` if the new edges would make a model depend on itself.
+    pub async fn set_dependencies(&self, id: Uuid, depends_on: Vec<Uuid>) -> Result<(), Error> {
+        let mut guard = self.state.write().await;
+        let state = &mut *guard;
+        if !state.monitored_models.contains_key(&id) {
+            return Err(Error::ModelNotFound(id));
+        }
+        for dep in &depends_on {
+            if !state.monitored_models.contains_key(dep) {
+                return Err(Error::ModelNotFound(*dep));
+            }
+            if reaches(&state.monitored_models, *dep, id) {
+                return Err(Error::DependencyCycle(id));
+            }
+        }
+        
+        let mut depends_on = depends_on;
+        depends_on.sort();
+        depends_on.dedup();
+        Arc::make_mut(state.monitored_models.get_mut(&id).expect("checked above")).depends_on = depends_on;
+        let transitions = propagate_dependencies(&mut state.monitored_models, &state.config, self.now());
+        recompute_metrics(state);
+        let config = state.config.clone();
+        drop(guard);
+        
+        self.emit(crate::StateEvent::ModelUpdated(id));
+        self.finish_transitions(&config, transitions);
+        Ok(())
+    }
+    
+    /// Upstream models `id` depends on
+    pub async fn dependencies(&self, id: Uuid) -> Result<Vec<Uuid>, Error> {
+        let state = self.state.load();
+        match state.monitored_models.get(&id) {
+            Some(model) => Ok(model.depends_on.clone()),
+            None => Err(Error::ModelNotFound(id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PluginConfig;
+
+    #[tokio::test]
+    async fn test_critical_dependency_degrades_downstream() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            auto_remediation: false,
+            ..Default::default()
+        }).await.unwrap();
+        let a = plugin.add_model("features".to_string(), "llm".to_string()).await.unwrap();
+        let b = plugin.add_model("ranker".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_dependencies(b, vec![a]).await.unwrap();
+        plugin.record_performance(a, 0.95).await.unwrap();
+        plugin.record_performance(b, 0.95).await.unwrap();
+        
+        plugin.record_performance(a, 0.2).await.unwrap();
+        assert_eq!(plugin.get_model(b).await.unwrap().status, ModelStatus::Degraded);
+        
+        // B's own good scores don't clear the cascade while A is critical
+        assert_eq!(plugin.record_performance(b, 0.95).await.unwrap(), ModelStatus::Degraded);
+        
+        plugin.record_performance(a, 0.95).await.unwrap();
+        assert_eq!(plugin.get_model(b).await.unwrap().status, ModelStatus::Healthy);
+        
+        let state = plugin.state.read().await;
+        assert!(state.metrics_consistent());
+    }
+
+    #[tokio::test]
+    async fn test_dependency_cycles_are_rejected() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        let b = plugin.add_model("b".to_string(), "llm".to_string()).await.unwrap();
+        let c = plugin.add_model("c".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_dependencies(b, vec![a]).await.unwrap();
+        plugin.set_dependencies(c, vec![b]).await.unwrap();
+        
+        assert!(matches!(plugin.set_dependencies(a, vec![c]).await, Err(Error::DependencyCycle(id)) if id == a));
+        assert!(matches!(plugin.set_dependencies(a, vec![a]).await, Err(Error::DependencyCycle(_))));
+        assert!(matches!(plugin.set_dependencies(a, vec![Uuid::new_v4()]).await, Err(Error::ModelNotFound(_))));
+        assert!(plugin.dependencies(a).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_removing_critical_dependency_clears_cascade() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        let b = plugin.add_model("b".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(b, 0.95).await.unwrap();
+        plugin.record_performance(a, 0.2).await.unwrap();
+        plugin.set_dependencies(b, vec![a]).await.unwrap();
+        assert_eq!(plugin.get_model(b).await.unwrap().status, ModelStatus::Degraded);
+        
+        plugin.remove_model(a).await.unwrap();
+        
+        let model = plugin.get_model(b).await.unwrap();
+        assert!(model.depends_on.is_empty());
+        assert_eq!(model.status, ModelStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_offline_dependency_degrades_downstream() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        let b = plugin.add_model("b".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(b, 0.95).await.unwrap();
+        plugin.state.write().await.model_mut(a).unwrap().status = ModelStatus::Offline;
+        plugin.set_dependencies(b, vec![a]).await.unwrap();
+        assert_eq!(plugin.get_model(b).await.unwrap().status, ModelStatus::Degraded);
+        
+        // A soft-deleted upstream no longer cascades
+        plugin.soft_delete(a).await.unwrap();
+        assert_eq!(plugin.get_model(b).await.unwrap().status, ModelStatus::Healthy);
+    }
+}
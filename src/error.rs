@@ -0,0 +1,144 @@
+//! Error type for plugin operations
+
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use crate::{Feature, PricingTierName};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("no monitored model with id {0}")]
+    ModelNotFound(Uuid),
+    
+    #[error("model {0} is paused and does not accept scores")]
+    ModelPaused(Uuid),
+    
+    #[error("model {0} is deleted and does not accept scores")]
+    ModelDeleted(Uuid),
+    
+    #[error("dependencies of model {0} would form a cycle")]
+    DependencyCycle(Uuid),
+    
+    #[error("a model named '{0}' is already being monitored")]
+    DuplicateName(String),
+    
+    #[error("model spec {index} ('{name}') was rejected: {source}")]
+    InvalidSpec {
+        index: usize,
+        name: String,
+        source: Box<Error>,
+    },
+    
+    #[error("model name must not be empty")]
+    EmptyName,
+    
+    #[error("tag must not be empty")]
+    EmptyTag,
+    
+    #[error("note text must not be empty")]
+    EmptyNote,
+    
+    #[error("fleet name must not be empty")]
+    EmptyFleet,
+    
+    #[error("invalid metadata: {0}")]
+    InvalidMetadata(String),
+    
+    #[error("the {tier:?} tier is limited to {limit} models")]
+    CapacityExceeded {
+        tier: PricingTierName,
+        limit: usize,
+    },
+    
+    #[error("{feature:?} is not included in the {tier:?} tier")]
+    FeatureUnavailable {
+        tier: PricingTierName,
+        feature: Feature,
+    },
+    
+    #[error("performance score {0} is outside the range 0.0-1.0")]
+    InvalidScore(f64),
+    
+    #[error("threshold {0} is outside the range 0.0-1.0")]
+    InvalidThreshold(f64),
+    
+    #[error("score range {min}-{max} must satisfy 0.0 <= min <= max <= 1.0")]
+    InvalidScoreRange { min: f64, max: f64 },
+    
+    #[error("check interval must be greater than 0 minutes")]
+    InvalidCheckInterval,
+    
+    #[error("importance {0} must be a finite, non-negative number")]
+    InvalidImportance(f32),
+    
+    #[cfg(feature = "synthetic")]
+    #[error("invalid synthetic score distribution: {0}")]
+    InvalidDistribution(String),
+    
+    #[error("metrics source failed for model {id}: {reason}")]
+    MetricsSource { id: Uuid, reason: String },
+    
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
+    
+    #[error("invalid value {value:?} for environment variable {name}: {reason}")]
+    InvalidEnvVar {
+        name: &'static str,
+        value: String,
+        reason: String,
+    },
+    
+    #[error("failed to read config file {}: {source}", path.display())]
+    ConfigFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    
+    #[error("config file {} is not valid TOML: {source}", path.display())]
+    ConfigParse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    
+    #[error("state file {} is corrupt or has an unexpected format: {source}", path.display())]
+    CorruptState {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    
+    #[cfg(feature = "binary-state")]
+    #[error("state file {} is corrupt or has an unexpected format: {source}", path.display())]
+    CorruptBinaryState {
+        path: PathBuf,
+        source: rmp_serde::decode::Error,
+    },
+    
+    #[error("state file {} has schema version {version}, newer than the supported {supported}", path.display())]
+    UnsupportedStateVersion {
+        path: PathBuf,
+        version: u64,
+        supported: u32,
+    },
+    
+    #[error("state file {} has inconsistent timestamps: {reason}", path.display())]
+    InvalidTimestamps {
+        path: PathBuf,
+        reason: String,
+    },
+    
+    #[error("failed to access state file {}: {source}", path.display())]
+    StateFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    
+    #[error("failed to serialize plugin state: {0}")]
+    Serialization(#[from] serde_json::Error),
+    
+    #[cfg(feature = "binary-state")]
+    #[error("failed to serialize plugin state: {0}")]
+    BinarySerialization(#[from] rmp_serde::encode::Error),
+    
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
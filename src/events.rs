@@ -0,0 +1,102 @@
+//! State change notifications
+//!
+//! Every mutation of the plugin state is followed by one or more
+//! `StateEvent`s on a broadcast channel, so UIs can react without polling.
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::{ModelPerformanceMonitoringPlugin, ModelStatus};
+
+/// Number of events buffered for each subscriber before it starts lagging
+pub(crate) const EVENT_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum StateEvent {
+    ModelAdded(Uuid),
+    ModelRemoved(Uuid),
+    /// A model's settings or remediation record changed without a status change
+    ModelUpdated(Uuid),
+    StatusChanged {
+        id: Uuid,
+        from: ModelStatus,
+        to: ModelStatus,
+    },
+    /// `system_metrics` was recomputed
+    MetricsUpdated,
+    ConfigUpdated,
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Subscribe to state change events.
+    ///
+    /// Events are emitted after the change is committed, so a subscriber that
+    /// reacts by reading state always sees the change. Each receiver buffers
+    /// up to `EVENT_CAPACITY` events; a receiver that falls further behind
+    /// gets `RecvError::Lagged(n)` on its next `recv`, skipping the `n` oldest
+    /// events, and should re-read the state it cares about to resynchronise.
+    pub fn subscribe(&self) -> broadcast::Receiver<StateEvent> {
+        self.events.subscribe()
+    }
+    
+    pub(crate) fn emit(&self, event: StateEvent) {
+        // Sending only fails when nobody is subscribed, which is fine
+        let _ = self.events.send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+    use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+    #[tokio::test]
+    async fn test_mutations_emit_events() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            auto_remediation: false,
+            ..Default::default()
+        }).await.unwrap();
+        let mut events = plugin.subscribe();
+        
+        let id = plugin.add_model("watched".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.5).await.unwrap();
+        plugin.record_performance(id, 0.5).await.unwrap();
+        plugin.remove_model(id).await.unwrap();
+        
+        let expected = vec![
+            StateEvent::ModelAdded(id),
+            StateEvent::MetricsUpdated,
+            StateEvent::StatusChanged {
+                id,
+                from: ModelStatus::Unknown,
+                to: ModelStatus::Critical,
+            },
+            StateEvent::MetricsUpdated,
+            // No status change on the second reading
+            StateEvent::MetricsUpdated,
+            StateEvent::ModelRemoved(id),
+            StateEvent::MetricsUpdated,
+        ];
+        for event in expected {
+            assert_eq!(events.recv().await.unwrap(), event);
+        }
+        assert_eq!(events.try_recv(), Err(TryRecvError::Empty));
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut events = plugin.subscribe();
+        
+        for _ in 0..EVENT_CAPACITY + 10 {
+            plugin.emit(StateEvent::MetricsUpdated);
+        }
+        
+        assert_eq!(events.recv().await, Err(RecvError::Lagged(10)));
+        assert_eq!(events.recv().await, Ok(StateEvent::MetricsUpdated));
+    }
+}
@@ -0,0 +1,393 @@
+//! Exporters for monitoring data
+//!
+//! Renders plugin state into formats consumed by external tooling.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, ModelStatus, MonitoredModel, PluginState};
+
+/// Label set of the per-model Prometheus series, chosen to keep the
+/// number of series manageable on large deployments
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MetricLabels {
+    /// One series per model keyed by `name`, so a model re-added under the
+    /// same name continues its series
+    ByName,
+    /// One series per model keyed by `model_id`, with `name` alongside
+    #[default]
+    ById,
+    /// One series per model labelled with its `fleet` and `name`
+    ByFleet,
+    /// No per-model series, only per-fleet rollups
+    Aggregate,
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Render system and per-model metrics in the Prometheus text exposition format
+    pub async fn prometheus_metrics(&self) -> String {
+        let state = self.state.load();
+        let counters = Counters {
+            alerts_dropped: self.dropped_alerts(),
+            alert_delivery_failures: self.failed_alert_deliveries(),
+            monitor_restarts: self.monitor_restarts(),
+        };
+        render_prometheus(&state, &counters)
+    }
+    
+    /// Render all monitored models, including tags and metadata, as a JSON array ordered by name
+    pub async fn export_json(&self) -> Result<String, Error> {
+        let state = self.state.load();
+        Ok(serde_json::to_string_pretty(&sorted_models(&state))?)
+    }
+    
+    /// Write every monitored model to `writer` as JSON Lines: one compact
+    /// object per line, ordered by name.
+    ///
+    /// Models are streamed one at a time from a single published snapshot
+    /// rather than collected into one document. The first serialization or write error
+    /// stops the export and is returned.
+    pub async fn export_jsonl<W: std::io::Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let state = self.state.load();
+        for model in sorted_models(&state) {
+            let mut line = serde_json::to_vec(model)?;
+            line.push(b'\n');
+            writer.write_all(&line)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+    
+    /// Render the monitored model table as CSV, one row per model ordered by name
+    pub async fn export_csv(&self) -> String {
+        let state = self.state.load();
+        let mut out = String::from("id,name,model_type,status,performance_score,created_at,last_check\n");
+        
+        for model in sorted_models(&state) {
+            let fields = [
+                model.id.to_string(),
+                model.name.clone(),
+                model.model_type.to_string(),
+                format!("{:?}", model.status),
+                model.performance_score.to_string(),
+                model.created_at.to_rfc3339(),
+                model.last_check.to_rfc3339(),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| escape_csv(field)).collect();
+            out.push_str(&row.join(","));
+            out.push('\n');
+        }
+        
+        out
+    }
+}
+
+/// Models in a stable order: by name, then id
+fn sorted_models(state: &PluginState) -> Vec<&MonitoredModel> {
+    let mut models: Vec<&MonitoredModel> = state.monitored_models.values().map(Arc::as_ref).collect();
+    models.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    models
+}
+
+/// Process-lifetime counters exported alongside the state
+struct Counters {
+    alerts_dropped: u64,
+    alert_delivery_failures: u64,
+    monitor_restarts: u64,
+}
+
+fn render_prometheus(state: &PluginState, counters: &Counters) -> String {
+    debug_assert!(state.metrics_consistent(), "system_metrics out of sync with monitored_models");
+    let metrics = &state.system_metrics;
+    let mut out = String::new();
+    
+    write_gauge(&mut out, "adios_models_total", "Number of monitored models", metrics.total_models);
+    write_gauge(&mut out, "adios_models_healthy", "Number of healthy models", metrics.healthy_models);
+    write_gauge(&mut out, "adios_models_degraded", "Number of degraded or critical models", metrics.degraded_models);
+    write_gauge(&mut out, "adios_models_unknown", "Number of models not yet checked", metrics.unknown_models);
+    write_gauge(&mut out, "adios_average_performance", "Mean performance score across all models", metrics.average_performance);
+    write_counter(&mut out, "adios_alerts_dropped_total", "Alerts dropped by the global rate limit", counters.alerts_dropped);
+    write_counter(
+        &mut out,
+        "adios_alert_delivery_failures_total",
+        "Alerts given up on after every delivery attempt failed",
+        counters.alert_delivery_failures,
+    );
+    write_counter(&mut out, "adios_monitor_restarts_total", "Monitoring loop restarts after a panic", counters.monitor_restarts);
+    
+    if state.config.metric_labels == MetricLabels::Aggregate {
+        write_fleet_rollups(&mut out, state);
+        return out;
+    }
+    
+    out.push_str("# HELP adios_model_performance_score Latest performance score per model\n");
+    out.push_str("# TYPE adios_model_performance_score gauge\n");
+    for model in sorted_models(state) {
+        let name = escape_label(&model.name);
+        let labels = match state.config.metric_labels {
+            MetricLabels::ByName => format!("name=\"{}\"", name),
+            MetricLabels::ById => format!("model_id=\"{}\",name=\"{}\"", model.id, name),
+            MetricLabels::ByFleet => format!("fleet=\"{}\",name=\"{}\"", escape_label(model.fleet.as_deref().unwrap_or_default()), name),
+            MetricLabels::Aggregate => unreachable!("aggregate mode has no per-model series"),
+        };
+        let _ = writeln!(out, "adios_model_performance_score{{{}}} {}", labels, model.performance_score);
+    }
+    
+    out
+}
+
+/// Model count and mean score per fleet, ordered by fleet. Models outside
+/// any fleet are rolled up under an empty `fleet` label; soft-deleted models
+/// are left out. The mean covers only models with a real score, so a fleet
+/// of `Unknown` models has no score series.
+fn write_fleet_rollups(out: &mut String, state: &PluginState) {
+    let mut fleets: BTreeMap<&str, (usize, usize, f64)> = BTreeMap::new();
+    for model in state.monitored_models.values().filter(|m| m.deleted_at.is_none()) {
+        let (count, scored, total) = fleets.entry(model.fleet.as_deref().unwrap_or_default()).or_default();
+        *count += 1;
+        if model.status != ModelStatus::Unknown {
+            *scored += 1;
+            *total += model.performance_score;
+        }
+    }
+    
+    out.push_str("# HELP adios_fleet_models Number of monitored models per fleet\n");
+    out.push_str("# TYPE adios_fleet_models gauge\n");
+    for (fleet, (count, _, _)) in &fleets {
+        let _ = writeln!(out, "adios_fleet_models{{fleet=\"{}\"}} {}", escape_label(fleet), count);
+    }
+    out.push_str("# HELP adios_fleet_performance_score Mean performance score per fleet\n");
+    out.push_str("# TYPE adios_fleet_performance_score gauge\n");
+    for (fleet, (_, scored, total)) in fleets.iter().filter(|(_, (_, scored, _))| *scored > 0) {
+        let _ = writeln!(out, "adios_fleet_performance_score{{fleet=\"{}\"}} {}", escape_label(fleet), total / *scored as f64);
+    }
+}
+
+fn write_gauge(out: &mut String, name: &str, help: &str, value: impl std::fmt::Display) {
+    write_metric(out, name, "gauge", help, value);
+}
+
+fn write_counter(out: &mut String, name: &str, help: &str, value: u64) {
+    write_metric(out, name, "counter", help, value);
+}
+
+fn write_metric(out: &mut String, name: &str, kind: &str, help: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+/// Escape a label value per the exposition format: backslash, quote and newline
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break (RFC 4180)
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_prometheus_metrics_output() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("say \"hi\" \\ bye".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.5).await.unwrap();
+        
+        let expected = format!(
+            "# HELP adios_models_total Number of monitored models
+# TYPE adios_models_total gauge
+adios_models_total 1
+# HELP adios_models_healthy Number of healthy models
+# TYPE adios_models_healthy gauge
+adios_models_healthy 0
+# HELP adios_models_degraded Number of degraded or critical models
+# TYPE adios_models_degraded gauge
+adios_models_degraded 1
+# HELP adios_models_unknown Number of models not yet checked
+# TYPE adios_models_unknown gauge
+adios_models_unknown 0
+# HELP adios_average_performance Mean performance score across all models
+# TYPE adios_average_performance gauge
+adios_average_performance 0.5
+# HELP adios_alerts_dropped_total Alerts dropped by the global rate limit
+# TYPE adios_alerts_dropped_total counter
+adios_alerts_dropped_total 0
+# HELP adios_alert_delivery_failures_total Alerts given up on after every delivery attempt failed
+# TYPE adios_alert_delivery_failures_total counter
+adios_alert_delivery_failures_total 0
+# HELP adios_monitor_restarts_total Monitoring loop restarts after a panic
+# TYPE adios_monitor_restarts_total counter
+adios_monitor_restarts_total 0
+# HELP adios_model_performance_score Latest performance score per model
+# TYPE adios_model_performance_score gauge
+adios_model_performance_score{{model_id=\"{}\",name=\"say \\\"hi\\\" \\\\ bye\"}} 0.5
+",
+            id
+        );
+        
+        assert_eq!(plugin.prometheus_metrics().await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_metrics_have_no_per_model_series() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            metric_labels: MetricLabels::Aggregate,
+            ..Default::default()
+        }).await.unwrap();
+        for (name, fleet, score) in [("ranker", Some("search"), 0.9), ("retriever", Some("search"), 0.6), ("fraud", None, 0.8)] {
+            let id = plugin.add_model(name.to_string(), "llm".to_string()).await.unwrap();
+            plugin.set_fleet(id, fleet).await.unwrap();
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        // Never checked, so counted but not averaged
+        for (name, fleet) in [("new-ranker", "search"), ("new-fleet", "vision")] {
+            let id = plugin.add_model(name.to_string(), "llm".to_string()).await.unwrap();
+            plugin.set_fleet(id, Some(fleet)).await.unwrap();
+        }
+        
+        let metrics = plugin.prometheus_metrics().await;
+        
+        assert!(!metrics.contains("adios_model_performance_score"));
+        assert!(!metrics.contains("model_id="));
+        assert!(!metrics.contains("ranker"));
+        assert!(metrics.contains("adios_fleet_models{fleet=\"search\"} 3\n"));
+        assert!(metrics.contains("adios_fleet_models{fleet=\"vision\"} 1\n"));
+        assert!(!metrics.contains("adios_fleet_performance_score{fleet=\"vision\"}"));
+        assert!(metrics.contains("adios_fleet_models{fleet=\"\"} 1\n"));
+        assert!(metrics.contains("adios_fleet_performance_score{fleet=\"search\"} 0.75\n"));
+        assert!(metrics.contains("adios_fleet_performance_score{fleet=\"\"} 0.8\n"));
+        assert!(metrics.contains("adios_models_total 5\n"));
+    }
+
+    #[tokio::test]
+    async fn test_per_model_label_modes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_fleet(id, Some("search")).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        for (labels, series) in [
+            (MetricLabels::ByName, "adios_model_performance_score{name=\"ranker\"} 0.9".to_string()),
+            (MetricLabels::ById, format!("adios_model_performance_score{{model_id=\"{}\",name=\"ranker\"}} 0.9", id)),
+            (MetricLabels::ByFleet, "adios_model_performance_score{fleet=\"search\",name=\"ranker\"} 0.9".to_string()),
+        ] {
+            plugin.update_config(crate::PluginConfig {
+                metric_labels: labels,
+                ..Default::default()
+            }).await.unwrap();
+            let metrics = plugin.prometheus_metrics().await;
+            assert!(metrics.contains(&format!("{}\n", series)), "{:?} rendered:\n{}", labels, metrics);
+            assert!(!metrics.contains("adios_fleet_models"));
+        }
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("plain"), "plain");
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[tokio::test]
+    async fn test_export_csv() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("churn, \"v2\"".to_string(), "classification").await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        let csv = plugin.export_csv().await;
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), "id,name,model_type,status,performance_score,created_at,last_check");
+        
+        let model = plugin.state.read().await.monitored_models[&id].clone();
+        let expected = format!(
+            "{},\"churn, \"\"v2\"\"\",classification,Healthy,0.9,{},{}",
+            id,
+            model.created_at.to_rfc3339(),
+            model.last_check.to_rfc3339()
+        );
+        assert_eq!(lines.next().unwrap(), expected);
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("plain"), "plain");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[tokio::test]
+    async fn test_export_json_includes_metadata() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("b-model".to_string(), "llm".to_string()).await.unwrap();
+        plugin.add_model("a-model".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_metadata(id, "owner", "ml-platform").await.unwrap();
+        
+        let json: serde_json::Value = serde_json::from_str(&plugin.export_json().await.unwrap()).unwrap();
+        assert_eq!(json[0]["name"], "a-model");
+        assert_eq!(json[1]["metadata"], serde_json::json!({"owner": "ml-platform"}));
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_one_line_per_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        for i in 0..5 {
+            let id = plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap();
+            plugin.record_performance(id, 0.9).await.unwrap();
+        }
+        
+        let mut out = Vec::new();
+        plugin.export_jsonl(&mut out).await.unwrap();
+        
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 5);
+        for (i, line) in lines.iter().enumerate() {
+            let model: MonitoredModel = serde_json::from_str(line).unwrap();
+            assert_eq!(model.name, format!("model-{}", i));
+        }
+    }
+
+    /// Fails every write
+    struct BrokenWriter;
+
+    impl std::io::Write for BrokenWriter {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk full"))
+        }
+        
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_surfaces_write_errors() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.add_model("model".to_string(), "llm".to_string()).await.unwrap();
+        
+        let err = plugin.export_jsonl(&mut BrokenWriter).await.unwrap_err();
+        assert!(matches!(err, Error::Io(_)));
+    }
+}
@@ -0,0 +1,104 @@
+//! Fleets
+//!
+//! A fleet is a named group of models, typically owned by one team. Unlike
+//! tags, a model belongs to at most one fleet, so per-fleet summaries
+//! partition the models rather than overlap.
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{Error, HealthSummary, ModelPerformanceMonitoringPlugin, StateEvent};
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Move a model into `fleet`, or out of any fleet with `None`. The fleet
+    /// name must not be empty.
+    pub async fn set_fleet(&self, id: Uuid, fleet: Option<&str>) -> Result<(), Error> {
+        let fleet = match fleet.map(str::trim) {
+            Some("") => return Err(Error::EmptyFleet),
+            fleet => fleet.map(str::to_string),
+        };
+        
+        let mut state = self.state.write().await;
+        let model = state.model_mut(id)?;
+        model.fleet = fleet;
+        drop(state);
+        
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(())
+    }
+    
+    /// Names of the fleets with at least one model, sorted
+    pub async fn list_fleets(&self) -> Vec<String> {
+        let state = self.state.load();
+        let mut fleets: Vec<String> = state.monitored_models.values().filter_map(|m| m.fleet.clone()).collect();
+        fleets.sort();
+        fleets.dedup();
+        fleets
+    }
+    
+    /// Aggregate health report over the models of one fleet
+    pub async fn fleet_summary(&self, fleet: &str) -> HealthSummary {
+        let state = self.state.load();
+        HealthSummary::from_models(
+            state
+                .monitored_models
+                .values()
+                .map(Arc::as_ref)
+                .filter(|m| m.fleet.as_deref() == Some(fleet)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+
+    #[tokio::test]
+    async fn test_fleet_summaries_are_independent() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let ranker = plugin.add_model("ranker".to_string(), ModelType::LLM).await.unwrap();
+        let retriever = plugin.add_model("retriever".to_string(), ModelType::Embedding).await.unwrap();
+        let fraud = plugin.add_model("fraud".to_string(), ModelType::Classification).await.unwrap();
+        plugin.add_model("unassigned".to_string(), ModelType::LLM).await.unwrap();
+        plugin.set_fleet(ranker, Some("search")).await.unwrap();
+        plugin.set_fleet(retriever, Some("search")).await.unwrap();
+        plugin.set_fleet(fraud, Some("risk")).await.unwrap();
+        plugin.record_performance(ranker, 0.9).await.unwrap();
+        plugin.record_performance(retriever, 0.7).await.unwrap();
+        plugin.record_performance(fraud, 0.2).await.unwrap();
+        
+        assert_eq!(plugin.list_fleets().await, vec!["risk", "search"]);
+        
+        let search = plugin.fleet_summary("search").await;
+        assert_eq!(search.total_models, 2);
+        assert_eq!(search.healthy_models, 1);
+        assert_eq!(search.degraded_models, 1);
+        assert_eq!(search.critical_models, 0);
+        assert!((search.average_performance - 0.8).abs() < 1e-6);
+        
+        let risk = plugin.fleet_summary("risk").await;
+        assert_eq!(risk.total_models, 1);
+        assert_eq!(risk.critical_models, 1);
+        assert_eq!(risk.worst_performer.unwrap().id, fraud);
+        
+        assert_eq!(plugin.fleet_summary("missing").await.total_models, 0);
+    }
+
+    #[tokio::test]
+    async fn test_model_belongs_to_one_fleet() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), ModelType::LLM).await.unwrap();
+        
+        plugin.set_fleet(id, Some("search")).await.unwrap();
+        plugin.set_fleet(id, Some("ads")).await.unwrap();
+        assert_eq!(plugin.fleet_summary("search").await.total_models, 0);
+        assert_eq!(plugin.fleet_summary("ads").await.total_models, 1);
+        
+        plugin.set_fleet(id, None).await.unwrap();
+        assert!(plugin.list_fleets().await.is_empty());
+        
+        assert!(matches!(plugin.set_fleet(id, Some(" ")).await, Err(Error::EmptyFleet)));
+        assert!(matches!(plugin.set_fleet(Uuid::new_v4(), None).await, Err(Error::ModelNotFound(_))));
+    }
+}
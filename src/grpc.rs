@@ -0,0 +1,221 @@
+//! gRPC control API (enabled with the `grpc` feature)
+//!
+//! Implements the `Monitoring` service from `proto/monitoring.proto` on top
+//! of the plugin's public methods, so remote calls go through the same
+//! validation, events and alerting as local ones. Plugin errors map onto
+//! gRPC status codes: unknown models are `NotFound`, rejected input is
+//! `InvalidArgument`.
+
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, MonitoredModel};
+
+pub mod proto {
+    tonic::include_proto!("adios.monitoring.v1");
+}
+
+use proto::monitoring_server::{Monitoring, MonitoringServer};
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Serve the gRPC control API on `addr` until the server fails
+    pub async fn serve_grpc(&self, addr: SocketAddr) -> Result<(), Error> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("Serving gRPC control API on {}", listener.local_addr()?);
+        Server::builder()
+            .add_service(self.grpc_service())
+            .serve_with_incoming(TcpIncoming::from(listener))
+            .await
+            .map_err(std::io::Error::other)?;
+        Ok(())
+    }
+    
+    fn grpc_service(&self) -> MonitoringServer<GrpcService> {
+        MonitoringServer::new(GrpcService { plugin: self.clone() })
+    }
+}
+
+pub struct GrpcService {
+    plugin: ModelPerformanceMonitoringPlugin,
+}
+
+#[tonic::async_trait]
+impl Monitoring for GrpcService {
+    async fn add_model(&self, request: Request<proto::AddModelRequest>) -> Result<Response<proto::AddModelResponse>, Status> {
+        let request = request.into_inner();
+        let id = self.plugin.add_model(request.name, request.model_type).await?;
+        Ok(Response::new(proto::AddModelResponse { id: id.to_string() }))
+    }
+    
+    async fn remove_model(&self, request: Request<proto::RemoveModelRequest>) -> Result<Response<proto::Model>, Status> {
+        let id = parse_id(&request.get_ref().id)?;
+        let model = self.plugin.remove_model(id).await?;
+        Ok(Response::new(to_proto(&model)))
+    }
+    
+    async fn record_performance(
+        &self,
+        request: Request<proto::RecordPerformanceRequest>,
+    ) -> Result<Response<proto::RecordPerformanceResponse>, Status> {
+        let id = parse_id(&request.get_ref().id)?;
+        let status = self.plugin.record_performance(id, request.get_ref().score).await?;
+        Ok(Response::new(proto::RecordPerformanceResponse {
+            status: format!("{:?}", status),
+        }))
+    }
+    
+    async fn list_models(&self, _request: Request<proto::ListModelsRequest>) -> Result<Response<proto::ListModelsResponse>, Status> {
+        let models = self.plugin.list_models(None).await;
+        Ok(Response::new(proto::ListModelsResponse {
+            models: models.iter().map(to_proto).collect(),
+        }))
+    }
+    
+    async fn get_metrics(&self, _request: Request<proto::GetMetricsRequest>) -> Result<Response<proto::Metrics>, Status> {
+        let state = self.plugin.state.load();
+        debug_assert!(state.metrics_consistent(), "system_metrics out of sync with monitored_models");
+        let metrics = &state.system_metrics;
+        Ok(Response::new(proto::Metrics {
+            total_models: metrics.total_models,
+            healthy_models: u64::from(metrics.healthy_models),
+            degraded_models: u64::from(metrics.degraded_models),
+            unknown_models: u64::from(metrics.unknown_models),
+            average_performance: metrics.average_performance,
+        }))
+    }
+}
+
+// `Status` is large, but it is what every handler returns anyway
+#[allow(clippy::result_large_err)]
+fn parse_id(id: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(id).map_err(|_| Status::invalid_argument(format!("'{}' is not a valid model id", id)))
+}
+
+fn to_proto(model: &MonitoredModel) -> proto::Model {
+    proto::Model {
+        id: model.id.to_string(),
+        name: model.name.clone(),
+        model_type: model.model_type.to_string(),
+        status: format!("{:?}", model.status),
+        performance_score: model.performance_score,
+        created_at: model.created_at.to_rfc3339(),
+        last_check: model.last_check.to_rfc3339(),
+    }
+}
+
+impl From<Error> for Status {
+    fn from(err: Error) -> Self {
+        let message = err.to_string();
+        match err {
+            Error::ModelNotFound(_) => Status::not_found(message),
+            Error::DuplicateName(_) => Status::already_exists(message),
+            Error::InvalidSpec { source, .. } => Status::new(Status::from(*source).code(), message),
+            Error::DependencyCycle(_) => Status::invalid_argument(message),
+            Error::EmptyName
+            | Error::EmptyTag
+            | Error::EmptyNote
+            | Error::EmptyFleet
+            | Error::InvalidMetadata(_)
+            | Error::InvalidScore(_)
+            | Error::InvalidScoreRange { .. }
+            | Error::InvalidThreshold(_)
+            | Error::InvalidCheckInterval
+            | Error::InvalidImportance(_)
+            | Error::InvalidConfig(_)
+            | Error::InvalidEnvVar { .. } => Status::invalid_argument(message),
+            #[cfg(feature = "synthetic")]
+            Error::InvalidDistribution(_) => Status::invalid_argument(message),
+            Error::CapacityExceeded { .. } => Status::resource_exhausted(message),
+            Error::MetricsSource { .. } => Status::unavailable(message),
+            Error::ModelPaused(_) | Error::ModelDeleted(_) | Error::FeatureUnavailable { .. } => Status::failed_precondition(message),
+            _ => Status::internal(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proto::monitoring_client::MonitoringClient;
+    use tonic::Code;
+
+    /// Start the service on an ephemeral port and return a connected client
+    async fn spawn_server(plugin: &ModelPerformanceMonitoringPlugin) -> MonitoringClient<tonic::transport::Channel> {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let service = plugin.grpc_service();
+        tokio::spawn(async move {
+            Server::builder()
+                .add_service(service)
+                .serve_with_incoming(TcpIncoming::from(listener))
+                .await
+                .unwrap()
+        });
+        MonitoringClient::connect(format!("http://{}", addr)).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_grpc_list_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("remote".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let mut client = spawn_server(&plugin).await;
+        
+        let response = client.list_models(proto::ListModelsRequest {}).await.unwrap().into_inner();
+        
+        assert_eq!(response.models.len(), 1);
+        let model = &response.models[0];
+        assert_eq!(model.id, id.to_string());
+        assert_eq!(model.name, "remote");
+        assert_eq!(model.status, "Healthy");
+        assert_eq!(model.performance_score, 0.9);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_control_and_error_codes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut client = spawn_server(&plugin).await;
+        
+        let id = client
+            .add_model(proto::AddModelRequest {
+                name: "remote".to_string(),
+                model_type: "llm".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .id;
+        let recorded = client
+            .record_performance(proto::RecordPerformanceRequest { id: id.clone(), score: 0.2 })
+            .await
+            .unwrap();
+        assert_eq!(recorded.into_inner().status, "Critical");
+        
+        // Scores travel as doubles, so they come back exactly as recorded
+        client.record_performance(proto::RecordPerformanceRequest { id: id.clone(), score: 0.123456789012 }).await.unwrap();
+        let listed = client.list_models(proto::ListModelsRequest {}).await.unwrap().into_inner();
+        assert_eq!(listed.models[0].performance_score, 0.123456789012);
+        let metrics = client.get_metrics(proto::GetMetricsRequest {}).await.unwrap().into_inner();
+        assert_eq!(metrics.total_models, 1);
+        
+        let err = client
+            .record_performance(proto::RecordPerformanceRequest { id: id.clone(), score: 1.5 })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        let err = client
+            .remove_model(proto::RemoveModelRequest { id: "not-a-uuid".to_string() })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        
+        client.remove_model(proto::RemoveModelRequest { id: id.clone() }).await.unwrap();
+        let err = client.remove_model(proto::RemoveModelRequest { id }).await.unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+    }
+}
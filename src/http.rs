@@ -0,0 +1,129 @@
+//! HTTP status API (enabled with the `http` feature)
+//!
+//! Serves read-only JSON views over the plugin's live state:
+//!
+//! - `GET /models` - every monitored model
+//! - `GET /models/{id}` - a single model, 400 for a malformed id, 404 if unknown
+//! - `GET /metrics` - the current `SystemMetrics`
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::state_cell::StateCell;
+use crate::{Error, ModelPerformanceMonitoringPlugin, MonitoredModel};
+
+type SharedState = Arc<StateCell>;
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Serve the status API on `addr` until the server fails
+    pub async fn serve_http(&self, addr: SocketAddr) -> Result<(), Error> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("Serving model status on http://{}", listener.local_addr()?);
+        axum::serve(listener, self.http_router()).await?;
+        Ok(())
+    }
+    
+    fn http_router(&self) -> Router {
+        Router::new()
+            .route("/models", get(list_models))
+            .route("/models/{id}", get(get_model))
+            .route("/metrics", get(get_metrics))
+            .with_state(self.state.clone())
+    }
+}
+
+async fn list_models(State(state): State<SharedState>) -> Json<Vec<MonitoredModel>> {
+    let state = state.load();
+    Json(state.monitored_models.values().map(Arc::as_ref).cloned().collect())
+}
+
+async fn get_model(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let Ok(id) = Uuid::parse_str(&id) else {
+        return error_response(StatusCode::BAD_REQUEST, format!("'{}' is not a valid model id", id));
+    };
+    
+    match state.load().monitored_models.get(&id) {
+        Some(model) => Json(model.as_ref()).into_response(),
+        None => error_response(StatusCode::NOT_FOUND, format!("no monitored model with id {}", id)),
+    }
+}
+
+async fn get_metrics(State(state): State<SharedState>) -> Response {
+    let state = state.load();
+    debug_assert!(state.metrics_consistent(), "system_metrics out of sync with monitored_models");
+    Json(state.system_metrics.clone()).into_response()
+}
+
+fn error_response(status: StatusCode, message: String) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Start the router on an ephemeral port and return its address
+    async fn spawn_server(plugin: &ModelPerformanceMonitoringPlugin) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let router = plugin.http_router();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        addr
+    }
+
+    /// Issue a bare HTTP/1.1 GET and return the status code and body
+    async fn get(addr: SocketAddr, path: &str) -> (u16, String) {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path);
+        stream.write_all(request.as_bytes()).await.unwrap();
+        
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap_or_default().to_string();
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn test_http_endpoints() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("served".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_metadata(id, "git_sha", "abc123").await.unwrap();
+        let addr = spawn_server(&plugin).await;
+        
+        let (status, body) = get(addr, "/models").await;
+        assert_eq!(status, 200);
+        let models: Vec<MonitoredModel> = serde_json::from_str(&body).unwrap();
+        assert_eq!(models.len(), 1);
+        
+        let (status, body) = get(addr, &format!("/models/{}", id)).await;
+        assert_eq!(status, 200);
+        let model = serde_json::from_str::<MonitoredModel>(&body).unwrap();
+        assert_eq!(model.id, id);
+        assert_eq!(model.metadata["git_sha"], "abc123");
+        
+        // Responses reflect live state
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let (_, body) = get(addr, "/metrics").await;
+        let metrics: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(metrics["healthy_models"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_http_model_errors() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let addr = spawn_server(&plugin).await;
+        
+        assert_eq!(get(addr, "/models/not-a-uuid").await.0, 400);
+        assert_eq!(get(addr, &format!("/models/{}", Uuid::new_v4())).await.0, 404);
+    }
+}
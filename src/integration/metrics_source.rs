@@ -0,0 +1,63 @@
+//! Pull-based score collection
+//!
+//! A `MetricsSource` lets the monitoring loop fetch each model's score from
+//! an external system (Prometheus, a database, an HTTP endpoint) on every
+//! check cycle instead of waiting for `record_performance` to be called.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{ModelPerformanceMonitoringPlugin, MonitoredModel};
+
+/// External system the monitoring loop pulls scores from
+#[async_trait]
+pub trait MetricsSource: Send + Sync {
+    /// Called once at the start of each check cycle, before any `fetch`, so
+    /// sources serving many models can load them all in one request
+    async fn refresh(&self) -> Result<()> {
+        Ok(())
+    }
+    
+    /// Current performance score for `model`, in the range 0.0-1.0
+    async fn fetch(&self, model: &MonitoredModel) -> Result<f64>;
+}
+
+/// Fixed scores keyed by model name; fetching an unknown model fails
+#[derive(Debug, Clone, Default)]
+pub struct StaticSource {
+    scores: HashMap<String, f64>,
+}
+
+impl StaticSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Serve `score` for the model called `name`
+    pub fn with_score(mut self, name: impl Into<String>, score: f64) -> Self {
+        self.scores.insert(name.into(), score);
+        self
+    }
+}
+
+#[async_trait]
+impl MetricsSource for StaticSource {
+    async fn fetch(&self, model: &MonitoredModel) -> Result<f64> {
+        self.scores
+            .get(&model.name)
+            .copied()
+            .ok_or_else(|| anyhow!("no score configured for '{}'", model.name))
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Register the source the monitoring loop fetches scores from each cycle
+    pub async fn set_metrics_source<S>(&self, source: S)
+    where
+        S: MetricsSource + 'static,
+    {
+        *self.metrics_source.write().await = Some(Arc::new(source));
+    }
+}
@@ -1,5 +1,9 @@
 //! Plugin integration module
 
 pub mod adios_integration;
+pub mod metrics_source;
+pub mod otlp_source;
 
 pub use adios_integration::*;
+pub use metrics_source::{MetricsSource, StaticSource};
+pub use otlp_source::{OtlpSource, OtlpSourceConfig};
@@ -0,0 +1,315 @@
+//! OpenTelemetry metrics ingestion
+//!
+//! `OtlpSource` polls an endpoint serving OTLP/HTTP JSON metrics (an
+//! `ExportMetricsServiceRequest` document) once per check cycle and reads
+//! each model's score from a configured gauge. A resource belongs to the
+//! model whose name or id equals the value of its `attribute_key` resource
+//! attribute; when several data points match, the latest wins.
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+use super::MetricsSource;
+use crate::MonitoredModel;
+
+/// Where to poll and which gauge to read
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OtlpSourceConfig {
+    /// URL returning OTLP JSON metrics
+    pub endpoint: String,
+    /// Name of the gauge holding the performance score
+    pub metric: String,
+    /// Resource attribute whose value is the model's name or id
+    #[serde(default = "default_attribute_key")]
+    pub attribute_key: String,
+}
+
+fn default_attribute_key() -> String {
+    "service.name".to_string()
+}
+
+/// Scores read from an OTLP/HTTP JSON metrics endpoint
+pub struct OtlpSource {
+    config: OtlpSourceConfig,
+    client: reqwest::Client,
+    /// Latest score per attribute value, from the last refresh
+    scores: Arc<Mutex<HashMap<String, f64>>>,
+}
+
+impl OtlpSource {
+    pub fn new(config: OtlpSourceConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+            scores: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl MetricsSource for OtlpSource {
+    async fn refresh(&self) -> Result<()> {
+        // Stale scores are never served if polling fails
+        self.scores.lock().unwrap().clear();
+        
+        let payload: ExportMetricsServiceRequest = self
+            .client
+            .get(&self.config.endpoint)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("polling {}", self.config.endpoint))?
+            .json()
+            .await
+            .with_context(|| format!("decoding OTLP metrics from {}", self.config.endpoint))?;
+        
+        *self.scores.lock().unwrap() = extract_gauge(&payload, &self.config.metric, &self.config.attribute_key);
+        Ok(())
+    }
+    
+    async fn fetch(&self, model: &MonitoredModel) -> Result<f64> {
+        let scores = self.scores.lock().unwrap();
+        scores
+            .get(&model.name)
+            .or_else(|| scores.get(&model.id.to_string()))
+            .copied()
+            .ok_or_else(|| anyhow!("no '{}' gauge for '{}' in the OTLP metrics", self.config.metric, model.name))
+    }
+}
+
+/// Latest value of gauge `metric` per value of resource attribute `key`
+fn extract_gauge(payload: &ExportMetricsServiceRequest, metric: &str, key: &str) -> HashMap<String, f64> {
+    let mut latest: HashMap<String, (u64, f64)> = HashMap::new();
+    for resource_metrics in &payload.resource_metrics {
+        let Some(owner) = resource_metrics.resource.attributes.iter().find(|a| a.key == key).and_then(|a| a.value.as_string()) else {
+            debug!("Skipping OTLP resource without a '{}' attribute", key);
+            continue;
+        };
+        
+        let points = resource_metrics
+            .scope_metrics
+            .iter()
+            .flat_map(|scope| &scope.metrics)
+            .filter(|m| m.name == metric)
+            .filter_map(|m| m.gauge.as_ref())
+            .flat_map(|gauge| &gauge.data_points);
+        for point in points {
+            let Some(value) = point.value() else {
+                debug!("Skipping '{}' data point without a numeric value for '{}'", metric, owner);
+                continue;
+            };
+            let time = point.time_unix_nano.as_u64().unwrap_or(0);
+            if latest.get(&owner).is_none_or(|(seen, _)| time >= *seen) {
+                latest.insert(owner.clone(), (time, value));
+            }
+        }
+    }
+    latest.into_iter().map(|(owner, (_, value))| (owner, value)).collect()
+}
+
+// The subset of the OTLP JSON encoding needed to read gauges. 64-bit
+// integers may be encoded as strings or numbers.
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportMetricsServiceRequest {
+    #[serde(default)]
+    resource_metrics: Vec<ResourceMetrics>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceMetrics {
+    #[serde(default)]
+    resource: Resource,
+    #[serde(default)]
+    scope_metrics: Vec<ScopeMetrics>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Resource {
+    #[serde(default)]
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyValue {
+    key: String,
+    #[serde(default)]
+    value: AnyValue,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AnyValue {
+    string_value: Option<String>,
+    int_value: Option<Int64>,
+}
+
+impl AnyValue {
+    fn as_string(&self) -> Option<String> {
+        self.string_value.clone().or_else(|| self.int_value.as_ref().map(|v| v.to_string()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScopeMetrics {
+    #[serde(default)]
+    metrics: Vec<Metric>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Metric {
+    name: String,
+    gauge: Option<Gauge>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Gauge {
+    #[serde(default)]
+    data_points: Vec<NumberDataPoint>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NumberDataPoint {
+    as_double: Option<f64>,
+    as_int: Option<Int64>,
+    #[serde(default)]
+    time_unix_nano: Int64,
+}
+
+impl NumberDataPoint {
+    fn value(&self) -> Option<f64> {
+        self.as_double.or_else(|| self.as_int.as_ref().and_then(|v| v.as_u64()).map(|v| v as f64))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Int64 {
+    Number(i64),
+    String(String),
+}
+
+impl Default for Int64 {
+    fn default() -> Self {
+        Int64::Number(0)
+    }
+}
+
+impl Int64 {
+    fn as_u64(&self) -> Option<u64> {
+        match self {
+            Int64::Number(n) => u64::try_from(*n).ok(),
+            Int64::String(s) => s.parse().ok(),
+        }
+    }
+}
+
+impl std::fmt::Display for Int64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Int64::Number(n) => write!(f, "{}", n),
+            Int64::String(s) => f.write_str(s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock_http_response;
+    use crate::{ModelPerformanceMonitoringPlugin, ModelStatus};
+
+    /// Canned payload in the OTLP/HTTP JSON encoding
+    fn payload(by_id: uuid::Uuid) -> String {
+        serde_json::json!({
+            "resourceMetrics": [
+                {
+                    "resource": {"attributes": [{"key": "service.name", "value": {"stringValue": "ranker"}}]},
+                    "scopeMetrics": [{"metrics": [
+                        {"name": "model.score", "gauge": {"dataPoints": [
+                            {"asDouble": 0.61, "timeUnixNano": "1700000000000000000"},
+                            {"asDouble": 0.93, "timeUnixNano": "1700000060000000000"}
+                        ]}},
+                        {"name": "model.latency", "gauge": {"dataPoints": [{"asDouble": 120.0}]}}
+                    ]}]
+                },
+                {
+                    "resource": {"attributes": [{"key": "service.name", "value": {"stringValue": by_id.to_string()}}]},
+                    "scopeMetrics": [{"metrics": [
+                        {"name": "model.score", "gauge": {"dataPoints": [{"asInt": "0", "timeUnixNano": 1700000000000000000u64}]}}
+                    ]}]
+                },
+                {
+                    "resource": {"attributes": [{"key": "service.name", "value": {"stringValue": "latency-only"}}]},
+                    "scopeMetrics": [{"metrics": [{"name": "model.latency", "gauge": {"dataPoints": [{"asDouble": 80.0}]}}]}]
+                },
+                {
+                    "resource": {"attributes": [{"key": "host.name", "value": {"stringValue": "node-1"}}]},
+                    "scopeMetrics": [{"metrics": [{"name": "model.score", "gauge": {"dataPoints": [{"asDouble": 0.5}]}}]}]
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_extract_gauge() {
+        let by_id = uuid::Uuid::new_v4();
+        let payload: ExportMetricsServiceRequest = serde_json::from_str(&payload(by_id)).unwrap();
+        
+        let scores = extract_gauge(&payload, "model.score", "service.name");
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores["ranker"], 0.93);
+        assert_eq!(scores[&by_id.to_string()], 0.0);
+        
+        let by_host = extract_gauge(&payload, "model.score", "host.name");
+        assert_eq!(by_host["node-1"], 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_check_cycle_ingests_otlp_metrics() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let ranker = plugin.add_model("ranker".to_string(), "llm".to_string()).await.unwrap();
+        let by_id = plugin.add_model("embedder".to_string(), "embedding".to_string()).await.unwrap();
+        let unmatched = plugin.add_model("latency-only".to_string(), "llm".to_string()).await.unwrap();
+        let (addr, mut requests) = mock_http_response(200, payload(by_id)).await;
+        plugin.set_metrics_source(OtlpSource::new(OtlpSourceConfig {
+            endpoint: format!("http://{}/v1/metrics", addr),
+            metric: "model.score".to_string(),
+            attribute_key: default_attribute_key(),
+        })).await;
+        
+        plugin.run_checks().await;
+        
+        // One poll serves the whole cycle
+        assert_eq!(requests.recv().await.unwrap().request_line, "GET /v1/metrics HTTP/1.1");
+        assert!(requests.try_recv().is_err());
+        assert_eq!(plugin.get_model(ranker).await.unwrap().performance_score, 0.93);
+        assert_eq!(plugin.get_model(by_id).await.unwrap().status, ModelStatus::Critical);
+        assert_eq!(plugin.get_model(unmatched).await.unwrap().status, ModelStatus::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_failed_poll_serves_no_scores() {
+        let (addr, _requests) = mock_http_response(500, String::new()).await;
+        let source = OtlpSource::new(OtlpSourceConfig {
+            endpoint: format!("http://{}/v1/metrics", addr),
+            metric: "model.score".to_string(),
+            attribute_key: default_attribute_key(),
+        });
+        source.scores.lock().unwrap().insert("ranker".to_string(), 0.9);
+        
+        assert!(source.refresh().await.is_err());
+        let model = crate::test_support::sample_model("ranker", 0.9, ModelStatus::Healthy);
+        assert!(source.fetch(&model).await.is_err());
+    }
+}
@@ -0,0 +1,391 @@
+//! JSON-RPC 2.0 admin socket (Unix only)
+//!
+//! Local scripting interface over a Unix domain socket, one JSON-RPC
+//! request (or batch) per line, one response per line. Parameters are
+//! passed by name:
+//!
+//! - `add_model` `{name, model_type, external_id?}` - the new model's id
+//! - `remove_model` `{id}` - the removed model
+//! - `record_performance` `{id, score}` - the model's new status
+//! - `update_config` `{config}` - `null` once applied
+//! - `list_models` `{status?}`, `get_model` `{id}`, `get_config`,
+//!   `health_summary` `{tag?}`, `count_by_status`
+//!
+//! Plugin errors map onto the server error range: unknown models are
+//! `-32001`, duplicate names `-32002`, rejected input is the standard
+//! `-32602` invalid params.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, ModelStatus, PluginConfig};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+const MODEL_NOT_FOUND: i64 = -32001;
+const ALREADY_EXISTS: i64 = -32002;
+const CAPACITY_EXCEEDED: i64 = -32003;
+const UNAVAILABLE: i64 = -32004;
+const FAILED_PRECONDITION: i64 = -32005;
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Serve the JSON-RPC admin API on the Unix socket at `path` until
+    /// accepting fails. A stale socket file left at `path` is replaced, and
+    /// the new one is only accessible to its owner.
+    pub async fn serve_admin_socket(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        }
+        let listener = UnixListener::bind(path)?;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        info!("Serving JSON-RPC admin API on {}", path.display());
+        
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let plugin = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = plugin.serve_admin_connection(stream).await {
+                    debug!("Admin connection closed: {}", e);
+                }
+            });
+        }
+    }
+    
+    async fn serve_admin_connection(&self, stream: UnixStream) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            
+            if let Some(response) = self.handle_rpc(&line).await {
+                let mut out = response.to_string();
+                out.push('\n');
+                writer.write_all(out.as_bytes()).await?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Answer one line of input, `None` if it held only notifications
+    async fn handle_rpc(&self, line: &str) -> Option<Value> {
+        let message = match serde_json::from_str::<Value>(line) {
+            Ok(message) => message,
+            Err(e) => return Some(error_response(Value::Null, RpcError::new(PARSE_ERROR, e.to_string()))),
+        };
+        
+        match message {
+            Value::Array(batch) if batch.is_empty() => {
+                Some(error_response(Value::Null, RpcError::new(INVALID_REQUEST, "empty batch")))
+            }
+            Value::Array(batch) => {
+                let mut responses = Vec::with_capacity(batch.len());
+                for request in batch {
+                    responses.extend(self.handle_request(request).await);
+                }
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            request => self.handle_request(request).await,
+        }
+    }
+    
+    async fn handle_request(&self, request: Value) -> Option<Value> {
+        let raw_id = request.get("id").cloned().unwrap_or(Value::Null);
+        let request = match serde_json::from_value::<Request>(request) {
+            Ok(request) if request.jsonrpc == "2.0" => request,
+            _ => return Some(error_response(raw_id, RpcError::new(INVALID_REQUEST, "not a JSON-RPC 2.0 request"))),
+        };
+        
+        let result = self.call(&request.method, request.params).await;
+        if let Err(e) = &result {
+            warn!("Admin call '{}' failed: {}", request.method, e.message);
+        }
+        // Requests without an id are notifications and get no response
+        let id = request.id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "result": result, "id": id }),
+            Err(error) => error_response(id, error),
+        })
+    }
+    
+    async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "add_model" => {
+                let AddModelParams { name, model_type, external_id } = parse_params(params)?;
+                reply(self.add_model_with_external_id(name, model_type, external_id).await?)
+            }
+            "remove_model" => {
+                let IdParams { id } = parse_params(params)?;
+                reply(self.remove_model(id).await?)
+            }
+            "record_performance" => {
+                let RecordParams { id, score } = parse_params(params)?;
+                reply(self.record_performance(id, score).await?)
+            }
+            "update_config" => {
+                let ConfigParams { config } = parse_params(params)?;
+                reply(self.update_config(config).await?)
+            }
+            "list_models" => {
+                let ListParams { status } = parse_params(params)?;
+                reply(self.list_models(status).await)
+            }
+            "get_model" => {
+                let IdParams { id } = parse_params(params)?;
+                reply(self.get_model(id).await.ok_or(Error::ModelNotFound(id))?)
+            }
+            "get_config" => reply(&*self.current_config()),
+            "health_summary" => {
+                let SummaryParams { tag } = parse_params(params)?;
+                reply(self.health_summary(tag.as_deref()).await)
+            }
+            "count_by_status" => reply(self.count_by_status().await),
+            _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("no method '{}'", method))),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Request {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+    // Absent for notifications; `Some(Value::Null)` for an explicit null id
+    #[serde(default, deserialize_with = "present")]
+    id: Option<Value>,
+}
+
+fn present<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<Error> for RpcError {
+    fn from(err: Error) -> Self {
+        let message = err.to_string();
+        let code = match err {
+            Error::ModelNotFound(_) => MODEL_NOT_FOUND,
+            Error::DuplicateName(_) => ALREADY_EXISTS,
+            Error::InvalidSpec { source, .. } => Self::from(*source).code,
+            Error::DependencyCycle(_)
+            | Error::EmptyName
+            | Error::EmptyTag
+            | Error::EmptyNote
+            | Error::EmptyFleet
+            | Error::InvalidMetadata(_)
+            | Error::InvalidScore(_)
+            | Error::InvalidScoreRange { .. }
+            | Error::InvalidThreshold(_)
+            | Error::InvalidCheckInterval
+            | Error::InvalidImportance(_)
+            | Error::InvalidConfig(_)
+            | Error::InvalidEnvVar { .. } => INVALID_PARAMS,
+            #[cfg(feature = "synthetic")]
+            Error::InvalidDistribution(_) => INVALID_PARAMS,
+            Error::CapacityExceeded { .. } => CAPACITY_EXCEEDED,
+            Error::MetricsSource { .. } => UNAVAILABLE,
+            Error::ModelPaused(_) | Error::ModelDeleted(_) | Error::FeatureUnavailable { .. } => FAILED_PRECONDITION,
+            _ => INTERNAL_ERROR,
+        };
+        Self::new(code, message)
+    }
+}
+
+#[derive(Deserialize)]
+struct AddModelParams {
+    name: String,
+    model_type: String,
+    #[serde(default)]
+    external_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IdParams {
+    id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct RecordParams {
+    id: Uuid,
+    score: f64,
+}
+
+#[derive(Deserialize)]
+struct ConfigParams {
+    config: PluginConfig,
+}
+
+#[derive(Deserialize)]
+struct ListParams {
+    #[serde(default)]
+    status: Option<ModelStatus>,
+}
+
+#[derive(Deserialize)]
+struct SummaryParams {
+    #[serde(default)]
+    tag: Option<String>,
+}
+
+/// Named parameters for a method; omitted params count as `{}`
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn reply(result: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(result).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({ "jsonrpc": "2.0", "error": error, "id": id })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::Lines;
+    use tokio::net::unix::OwnedReadHalf;
+    use tokio::net::unix::OwnedWriteHalf;
+
+    struct Client {
+        lines: Lines<BufReader<OwnedReadHalf>>,
+        writer: OwnedWriteHalf,
+    }
+
+    impl Client {
+        async fn send(&mut self, request: Value) -> Value {
+            self.writer.write_all(format!("{}\n", request).as_bytes()).await.unwrap();
+            let line = self.lines.next_line().await.unwrap().unwrap();
+            serde_json::from_str(&line).unwrap()
+        }
+        
+        async fn call(&mut self, method: &str, params: Value) -> Value {
+            self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 })).await
+        }
+    }
+
+    /// Serve the plugin on a socket in a fresh temp dir and connect to it
+    async fn connect(plugin: &ModelPerformanceMonitoringPlugin) -> Client {
+        let path = std::env::temp_dir().join(format!("adios-mpm-admin-{}.sock", Uuid::new_v4()));
+        let server = plugin.clone();
+        let socket = path.clone();
+        tokio::spawn(async move { server.serve_admin_socket(socket).await.unwrap() });
+        
+        for _ in 0..100 {
+            if let Ok(stream) = UnixStream::connect(&path).await {
+                let (reader, writer) = stream.into_split();
+                return Client {
+                    lines: BufReader::new(reader).lines(),
+                    writer,
+                };
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("admin socket never came up");
+    }
+
+    #[tokio::test]
+    async fn test_admin_socket_list_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("scripted".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let mut client = connect(&plugin).await;
+        
+        let response = client.call("list_models", Value::Null).await;
+        
+        assert_eq!(response["jsonrpc"], "2.0");
+        assert_eq!(response["id"], 1);
+        let models = response["result"].as_array().unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0]["id"], id.to_string());
+        assert_eq!(models[0]["name"], "scripted");
+        assert_eq!(models[0]["status"], "Healthy");
+    }
+
+    #[tokio::test]
+    async fn test_admin_socket_control_and_error_codes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut client = connect(&plugin).await;
+        
+        let added = client.call("add_model", json!({ "name": "scripted", "model_type": "llm" })).await;
+        let id = added["result"].as_str().unwrap().to_string();
+        let recorded = client.call("record_performance", json!({ "id": id, "score": 0.2 })).await;
+        assert_eq!(recorded["result"], "Critical");
+        
+        let config = PluginConfig {
+            check_interval_minutes: 7,
+            ..Default::default()
+        };
+        let updated = client.call("update_config", json!({ "config": config })).await;
+        assert_eq!(updated["result"], Value::Null);
+        assert_eq!(plugin.state.read().await.config.check_interval_minutes, 7);
+        
+        let duplicate = client.call("add_model", json!({ "name": "scripted", "model_type": "llm" })).await;
+        assert_eq!(duplicate["error"]["code"], ALREADY_EXISTS);
+        let out_of_range = client.call("record_performance", json!({ "id": id, "score": 1.5 })).await;
+        assert_eq!(out_of_range["error"]["code"], INVALID_PARAMS);
+        let malformed = client.call("remove_model", json!({ "id": "not-a-uuid" })).await;
+        assert_eq!(malformed["error"]["code"], INVALID_PARAMS);
+        let unknown = client.call("reboot", Value::Null).await;
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+        
+        client.call("remove_model", json!({ "id": id })).await;
+        let missing = client.call("get_model", json!({ "id": id })).await;
+        assert_eq!(missing["error"]["code"], MODEL_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_admin_socket_batches_notifications_and_parse_errors() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut client = connect(&plugin).await;
+        
+        let batch = client
+            .send(json!([
+                { "jsonrpc": "2.0", "method": "add_model", "params": { "name": "quiet", "model_type": "llm" } },
+                { "jsonrpc": "2.0", "method": "count_by_status", "id": "counts" },
+                { "jsonrpc": "1.0", "method": "get_config", "id": 3 },
+            ]))
+            .await;
+        let responses = batch.as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], "counts");
+        assert_eq!(responses[0]["result"]["Unknown"], 1);
+        assert_eq!(responses[1]["id"], 3);
+        assert_eq!(responses[1]["error"]["code"], INVALID_REQUEST);
+        
+        client.writer.write_all(b"{not json\n").await.unwrap();
+        let line = client.lines.next_line().await.unwrap().unwrap();
+        let response: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+        assert_eq!(response["id"], Value::Null);
+    }
+}
@@ -1,23 +1,157 @@
+mod alerting;
+mod analytics;
+mod archive;
+mod audit;
+mod bulk;
+mod clock;
+mod config;
+mod dependencies;
+mod error;
+mod events;
+mod export;
+mod fleets;
+#[cfg(feature = "grpc")]
+mod grpc;
+#[cfg(feature = "http")]
+mod http;
 mod integration;
+#[cfg(unix)]
+mod jsonrpc;
+mod maintenance;
+mod monitor;
+mod notes;
+mod persistence;
+mod pushgateway;
+#[cfg(feature = "hot-reload")]
+mod reload;
+mod remediation;
+mod report;
+#[cfg(feature = "schema")]
+mod schema;
+mod sharding;
+mod shutdown;
+mod signing;
+mod soft_delete;
+mod state_cell;
+mod strategy;
+mod summary;
+#[cfg(feature = "synthetic")]
+mod synthetic;
+mod timeseries;
+mod ui;
+#[cfg(test)]
+mod test_support;
 // AdiOS Model Performance Monitoring Plugin
 // 
 // Enterprise model performance monitoring and auto-improvement service.
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use tokio::sync::RwLock;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch, Notify, RwLock};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
-use tracing::info;
+use tracing::{debug, error, info, warn, Span};
+
+pub use alerting::{format_alert, AggregateAlert, Alert, AlertFormat, BackoffPolicy, Severity};
+pub use archive::ArchivedModel;
+pub use audit::AuditRecord;
+pub use bulk::ModelSpec;
+pub use clock::{Clock, MockClock, SystemClock};
+pub use analytics::{AnomalyReport, DriftReport, ModelComparison, Trend};
+pub use config::{ConfigChange, PluginConfig};
+pub use error::Error;
+pub use events::StateEvent;
+pub use export::MetricLabels;
+pub use integration::{MetricsSource, OtlpSource, OtlpSourceConfig, StaticSource};
+pub use maintenance::MaintenanceWindow;
+pub use monitor::HealthCheck;
+pub use notes::Note;
+pub use persistence::StateFormat;
+pub use signing::{sign_payload, verify_payload, AlertSecret, SignedAlert, SIGNATURE_HEADER};
+pub use remediation::{NoopRemediation, RemediationHandler, RemediationOutcome, RemediationRecord};
+#[cfg(feature = "schema")]
+pub use schema::{json_schema_document, json_schemas};
+pub use strategy::{BandStrategy, StatusStrategy, ThresholdStrategy};
+pub use summary::{HealthSummary, ModelScore, Ranking};
+#[cfg(feature = "synthetic")]
+pub use synthetic::{ScoreDistribution, SyntheticGenerator};
+pub use timeseries::TimeSeriesStore;
 
 /// Main plugin structure for AdiOS Model Performance Monitoring
+///
+/// Cloning is cheap and yields a handle onto the same shared state, which is
+/// how background tasks such as the monitoring loop reach the plugin.
+#[derive(Clone)]
 pub struct ModelPerformanceMonitoringPlugin {
     /// Plugin metadata and configuration
     info: PluginInfo,
     
-    /// Current state of the plugin
-    state: RwLock<PluginState>,
+    /// Current state of the plugin; hot read paths load its lock-free snapshot
+    state: Arc<state_cell::StateCell>,
+    
+    /// Callback invoked for every model on each monitoring tick
+    health_check: Arc<RwLock<Option<HealthCheck>>>,
+    
+    /// Signals the monitoring loop to stop
+    shutdown: Arc<Notify>,
+    
+    /// Asks `run` to shut the plugin down
+    shutdown_requested: Arc<Notify>,
+    
+    /// Set once the plugin shuts down; background tasks such as synthetic
+    /// generators and the config watcher stop on it
+    stopping: Arc<watch::Sender<bool>>,
+    
+    /// Wakes the monitoring loop after a configuration change
+    config_changed: Arc<Notify>,
+    
+    /// Client used for outbound webhook deliveries
+    http_client: reqwest::Client,
+    
+    /// When an alert last went out for each model and new status, for `alert_cooldown_minutes`
+    last_alerts: Arc<std::sync::Mutex<alerting::AlertLog>>,
+    
+    /// Alerts given up on after every delivery attempt failed
+    failed_deliveries: Arc<std::sync::atomic::AtomicU64>,
+    
+    /// Global cap on outbound alerts, for `alert_rate_limit_per_minute`
+    alert_limiter: Arc<std::sync::Mutex<alerting::AlertRateLimiter>>,
+    
+    /// Handler invoked when a model goes critical and auto-remediation is on
+    remediation_handler: Arc<RwLock<Arc<dyn RemediationHandler>>>,
+    
+    /// Where the monitoring loop pulls scores from, if anywhere
+    metrics_source: Arc<RwLock<Option<Arc<dyn MetricsSource>>>>,
+    
+    /// Policy mapping recorded scores to statuses
+    status_strategy: Arc<RwLock<Arc<dyn StatusStrategy>>>,
+    
+    /// When metrics were last pushed to the pushgateway
+    last_push: Arc<std::sync::Mutex<Option<tokio::time::Instant>>>,
+    
+    /// Next-check slots of the running monitoring loop
+    schedule: Arc<std::sync::Mutex<HashMap<Uuid, monitor::ScheduleSlot>>>,
+    
+    /// Times the monitoring loop was restarted after panicking
+    monitor_restarts: Arc<std::sync::atomic::AtomicU64>,
+    
+    /// Models with a remediation currently running
+    remediating: Arc<std::sync::Mutex<HashSet<Uuid>>>,
+    
+    /// Publishes state changes to subscribers
+    events: broadcast::Sender<StateEvent>,
+    
+    /// Time-ordered score samples for range queries, kept outside the persisted state
+    timeseries: Arc<RwLock<TimeSeriesStore>>,
+    
+    /// Where every wall-clock time read comes from
+    clock: Arc<std::sync::RwLock<Arc<dyn Clock>>>,
+    
+    /// Queue feeding the background audit log writer, started on first use
+    audit_log: Arc<std::sync::Mutex<Option<audit::AuditSender>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,50 +165,424 @@ pub struct PluginInfo {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PluginState {
-    /// Currently monitored models
-    pub monitored_models: HashMap<Uuid, MonitoredModel>,
+    /// Currently monitored models, shared with published snapshots until
+    /// they are next modified
+    pub monitored_models: HashMap<Uuid, Arc<MonitoredModel>>,
     
     /// System metrics and health
     pub system_metrics: SystemMetrics,
     
     /// Plugin configuration
     pub config: PluginConfig,
+    
+    /// Removed models, kept up to `archive_capacity`
+    #[serde(default)]
+    pub archived_models: HashMap<Uuid, Arc<ArchivedModel>>,
+    
+    /// Monitored model ids by `external_id`, rebuilt from the models on load
+    #[serde(skip)]
+    pub external_ids: Arc<HashMap<String, Uuid>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MonitoredModel {
     pub id: Uuid,
     pub name: String,
-    pub model_type: String,
+    pub model_type: ModelType,
     pub status: ModelStatus,
     pub created_at: DateTime<Utc>,
     pub last_check: DateTime<Utc>,
-    pub performance_score: f32,
+    pub performance_score: f64,
+    
+    /// Most recent score samples, oldest first, capped at `config.history_capacity`
+    #[serde(default)]
+    pub history: VecDeque<(DateTime<Utc>, f64)>,
+    
+    /// Result of the most recent auto-remediation attempt
+    #[serde(default)]
+    pub last_remediation: Option<RemediationRecord>,
+    
+    /// Per-model threshold used instead of `config.performance_threshold`
+    #[serde(default)]
+    pub threshold_override: Option<f64>,
+    
+    /// Free-form labels such as a project or environment, kept sorted
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+    
+    /// Arbitrary key/value annotations such as owner or dataset version
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    
+    /// When the model entered its current status
+    #[serde(default = "Utc::now")]
+    #[cfg_attr(feature = "schema", schemars(transform = schema::without_default))]
+    pub status_since: DateTime<Utc>,
+    
+    /// Cumulative time spent in each status before the current one
+    #[serde(default)]
+    pub status_durations: HashMap<ModelStatus, std::time::Duration>,
+    
+    /// Relative weight of this model in `system_health_score`
+    #[serde(default = "default_importance")]
+    pub importance: f32,
+    
+    /// Set during maintenance: the model is not checked and keeps its last
+    /// known score and status until resumed
+    #[serde(default)]
+    pub paused: bool,
+    
+    /// Minutes between checks of this model, overriding `check_interval_minutes`
+    #[serde(default)]
+    pub check_interval_override: Option<u32>,
+    
+    /// Healthy readings in a row, for `recovery_confirmations`
+    #[serde(default)]
+    pub consecutive_healthy: u32,
+    
+    /// Upstream models whose `Critical` status cascades to this one
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    
+    /// Set while the model is `Degraded` only because a dependency is `Critical`
+    #[serde(default)]
+    pub dependency_degraded: bool,
+    
+    /// When the model was soft-deleted: it is not checked, and is purged
+    /// once `soft_delete_ttl_hours` have passed unless restored
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+    
+    /// Operator annotations, oldest first, capped at `notes_capacity`
+    #[serde(default)]
+    pub notes: Vec<Note>,
+    
+    /// Caller-supplied idempotency key, unique across monitored models
+    #[serde(default)]
+    pub external_id: Option<String>,
+    
+    /// Fleet (e.g. owning team) the model belongs to, if any
+    #[serde(default)]
+    pub fleet: Option<String>,
+    
+    /// Check cycles in a row in which the model reported no score, or its
+    /// fetch failed; reset by every recorded score
+    #[serde(default)]
+    pub missed_checks: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+fn default_importance() -> f32 {
+    1.0
+}
+
+impl MonitoredModel {
+    /// A freshly added model with no score yet
+    fn new(name: String, model_type: ModelType, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            model_type,
+            status: ModelStatus::Unknown,
+            created_at: now,
+            last_check: now,
+            performance_score: 1.0,
+            history: VecDeque::new(),
+            last_remediation: None,
+            threshold_override: None,
+            tags: BTreeSet::new(),
+            metadata: BTreeMap::new(),
+            status_since: now,
+            status_durations: HashMap::new(),
+            importance: default_importance(),
+            paused: false,
+            check_interval_override: None,
+            consecutive_healthy: 0,
+            depends_on: Vec::new(),
+            dependency_degraded: false,
+            deleted_at: None,
+            notes: Vec::new(),
+            external_id: None,
+            fleet: None,
+            missed_checks: 0,
+        }
+    }
+    
+    /// Append a score sample, evicting the oldest ones beyond `capacity`
+    fn push_sample(&mut self, at: DateTime<Utc>, score: f64, capacity: usize) {
+        self.history.push_back((at, score));
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+    }
+    
+    /// Move to `status` at `at`, crediting the time since the last transition
+    /// to the outgoing status. Returns the previous status.
+    fn set_status(&mut self, status: ModelStatus, at: DateTime<Utc>) -> ModelStatus {
+        if status == self.status {
+            return status;
+        }
+        
+        let elapsed = (at - self.status_since).to_std().unwrap_or_default();
+        *self.status_durations.entry(self.status.clone()).or_default() += elapsed;
+        self.status_since = at;
+        std::mem::replace(&mut self.status, status)
+    }
+    
+    /// Total time spent in `status` up to `now`, including the current stretch
+    pub fn time_in_status(&self, status: &ModelStatus, now: DateTime<Utc>) -> std::time::Duration {
+        let mut total = self.status_durations.get(status).copied().unwrap_or_default();
+        if &self.status == status {
+            total += (now - self.status_since).to_std().unwrap_or_default();
+        }
+        total
+    }
+    
+    /// Fraction of monitored time spent `Healthy` up to `now`.
+    ///
+    /// Time spent `Unknown` before the first check doesn't count as monitored;
+    /// a model with no monitored time yet reports 1.0.
+    pub fn uptime_ratio(&self, now: DateTime<Utc>) -> f64 {
+        let monitored: std::time::Duration = [
+            ModelStatus::Healthy,
+            ModelStatus::Degraded,
+            ModelStatus::Critical,
+            ModelStatus::Offline,
+        ]
+        .iter()
+        .map(|status| self.time_in_status(status, now))
+        .sum();
+        if monitored.is_zero() {
+            return 1.0;
+        }
+        self.time_in_status(&ModelStatus::Healthy, now).as_secs_f64() / monitored.as_secs_f64()
+    }
+    
+    /// Score compared against the threshold when deriving status: the
+    /// exponentially smoothed score if `decay_alpha` is set, otherwise the
+    /// mean over `smoothing_window` samples
+    pub fn status_score(&self, config: &PluginConfig) -> f64 {
+        match config.decay_alpha {
+            Some(_) => self.performance_score,
+            None => self.smoothed_score(config.smoothing_window),
+        }
+    }
+    
+    /// Whether the model was added less than `warmup_minutes` before `now`:
+    /// it can go `Degraded` but not `Critical`, and sends no alerts
+    pub fn in_warmup(&self, config: &PluginConfig, now: DateTime<Utc>) -> bool {
+        now - self.created_at < chrono::Duration::minutes(i64::from(config.warmup_minutes))
+    }
+    
+    /// Whether the monitoring loop checks this model: neither paused nor soft-deleted
+    pub fn is_active(&self) -> bool {
+        !self.paused && self.deleted_at.is_none()
+    }
+    
+    /// Minutes between checks of this model
+    pub fn check_interval_minutes(&self, config: &PluginConfig) -> u32 {
+        self.check_interval_override.unwrap_or(config.check_interval_minutes)
+    }
+    
+    /// Threshold this model's scores are compared against
+    pub fn effective_threshold(&self, config: &PluginConfig) -> f64 {
+        self.threshold_override.unwrap_or(config.performance_threshold)
+    }
+    
+    /// Mean of the last `window` history samples, or the current score if there is no history
+    pub fn smoothed_score(&self, window: usize) -> f64 {
+        let n = window.min(self.history.len());
+        if n == 0 {
+            return self.performance_score;
+        }
+        self.history.iter().rev().take(n).map(|(_, score)| score).sum::<f64>() / n as f64
+    }
+}
+
+/// Kind of model being monitored.
+///
+/// Serialized as a lowercase string; strings that don't match a known kind
+/// round-trip through `Other`, so older free-form values keep loading.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "String", into = "String")]
+pub enum ModelType {
+    Classification,
+    Regression,
+    LLM,
+    Embedding,
+    Other(String),
+}
+
+impl From<String> for ModelType {
+    fn from(value: String) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "classification" => ModelType::Classification,
+            "regression" => ModelType::Regression,
+            "llm" => ModelType::LLM,
+            "embedding" => ModelType::Embedding,
+            _ => ModelType::Other(value),
+        }
+    }
+}
+
+impl From<&str> for ModelType {
+    fn from(value: &str) -> Self {
+        ModelType::from(value.to_string())
+    }
+}
+
+impl From<ModelType> for String {
+    fn from(value: ModelType) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::fmt::Display for ModelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelType::Classification => f.write_str("classification"),
+            ModelType::Regression => f.write_str("regression"),
+            ModelType::LLM => f.write_str("llm"),
+            ModelType::Embedding => f.write_str("embedding"),
+            ModelType::Other(other) => f.write_str(other),
+        }
+    }
+}
+
+/// Health of a model.
+///
+/// Statuses are ordered by severity, so `a > b` reads "a is worse than b":
+/// `Unknown < Healthy < Degraded < Critical < Offline`. `Unknown` sorts
+/// lowest because it carries no evidence of a problem, so it never outranks
+/// a status derived from a real score. `Offline` sorts above `Critical`
+/// since a model that cannot be reached serves nothing at all.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ModelStatus {
+    /// Added but not yet health-checked
+    Unknown,
     Healthy,
     Degraded,
     Critical,
     Offline,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ModelStatus {
+    /// Every status, least severe first
+    pub const ALL: [ModelStatus; 5] = [
+        ModelStatus::Unknown,
+        ModelStatus::Healthy,
+        ModelStatus::Degraded,
+        ModelStatus::Critical,
+        ModelStatus::Offline,
+    ];
+    
+    /// Rank of the status in the severity ordering, 0 for `Unknown` up to
+    /// 4 for `Offline`
+    pub fn severity(&self) -> u8 {
+        match self {
+            ModelStatus::Unknown => 0,
+            ModelStatus::Healthy => 1,
+            ModelStatus::Degraded => 2,
+            ModelStatus::Critical => 3,
+            ModelStatus::Offline => 4,
+        }
+    }
+    
+    /// Derive a status from a score relative to `threshold`.
+    ///
+    /// Never returns `Unknown`; that status is reserved for models which have
+    /// not reported a score yet. Scores at or above the threshold are healthy,
+    /// scores up to 0.25 below it are degraded, and anything further below is
+    /// critical.
+    pub fn from_score(score: f64, threshold: f64) -> Self {
+        if score >= threshold {
+            ModelStatus::Healthy
+        } else if score >= threshold - 0.25 {
+            ModelStatus::Degraded
+        } else {
+            ModelStatus::Critical
+        }
+    }
+}
+
+impl PartialOrd for ModelStatus {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ModelStatus {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.severity().cmp(&other.severity())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SystemMetrics {
     pub total_models: u64,
     pub healthy_models: u32,
     pub degraded_models: u32,
+    #[serde(default)]
+    pub unknown_models: u32,
     pub average_performance: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PluginConfig {
-    pub check_interval_minutes: u32,
-    pub performance_threshold: f64,
-    pub auto_remediation: bool,
-    pub alert_enabled: bool,
+impl SystemMetrics {
+    /// Derive every counter from the given models, leaving out soft-deleted
+    /// ones; the only way metrics are built. `Unknown` models have no real
+    /// score yet, so they are counted but left out of `average_performance`.
+    pub fn from_models<'a>(models: impl IntoIterator<Item = &'a MonitoredModel>) -> Self {
+        let mut metrics = SystemMetrics {
+            total_models: 0,
+            healthy_models: 0,
+            degraded_models: 0,
+            unknown_models: 0,
+            average_performance: 0.0,
+        };
+        
+        let (mut score_sum, mut scored) = (0.0, 0);
+        for model in models.into_iter().filter(|m| m.deleted_at.is_none()) {
+            metrics.total_models += 1;
+            match model.status {
+                ModelStatus::Healthy => metrics.healthy_models += 1,
+                ModelStatus::Degraded | ModelStatus::Critical => metrics.degraded_models += 1,
+                ModelStatus::Unknown => {
+                    metrics.unknown_models += 1;
+                    continue;
+                }
+                ModelStatus::Offline => {}
+            }
+            score_sum += model.performance_score;
+            scored += 1;
+        }
+        
+        if scored > 0 {
+            metrics.average_performance = score_sum / scored as f64;
+        }
+        metrics
+    }
+}
+
+impl PluginState {
+    /// Whether `system_metrics` agrees with `monitored_models`.
+    ///
+    /// Checked with `debug_assert!` wherever metrics are read so a write path
+    /// that forgets `recompute_metrics` fails loudly in tests.
+    pub(crate) fn metrics_consistent(&self) -> bool {
+        self.system_metrics == SystemMetrics::from_models(self.monitored_models.values().map(Arc::as_ref))
+    }
+    
+    /// Number of models counted against the tier's capacity: every model
+    /// that is not soft-deleted
+    pub(crate) fn live_models(&self) -> usize {
+        self.monitored_models.values().filter(|m| m.deleted_at.is_none()).count()
+    }
 }
 
 impl Default for PluginState {
@@ -85,20 +593,169 @@ impl Default for PluginState {
                 total_models: 0,
                 healthy_models: 0,
                 degraded_models: 0,
-                average_performance: 0.93,
-            },
-            config: PluginConfig {
-                check_interval_minutes: 5,
-                performance_threshold: 0.85,
-                auto_remediation: true,
-                alert_enabled: true,
+                unknown_models: 0,
+                average_performance: 0.0,
             },
+            config: PluginConfig::default(),
+            archived_models: HashMap::new(),
+            external_ids: Arc::default(),
+        }
+    }
+}
+
+/// A status change caused by a score, with the follow-up work it requires
+struct Transition {
+    alert: Alert,
+    /// Set when the model went critical and should be auto-remediated
+    remediate: Option<MonitoredModel>,
+    /// Set when the model is still warming up, so no alert is sent
+    in_warmup: bool,
+}
+
+/// Check that `score` can be recorded for model `id`, without touching any state
+fn check_score(models: &HashMap<Uuid, Arc<MonitoredModel>>, id: Uuid, score: f64) -> Result<(), Error> {
+    // NaN and infinities would otherwise poison averages
+    if !score.is_finite() || !(0.0..=1.0).contains(&score) {
+        return Err(Error::InvalidScore(score));
+    }
+    let model = models.get(&id).ok_or(Error::ModelNotFound(id))?;
+    if model.paused {
+        return Err(Error::ModelPaused(id));
+    }
+    if model.deleted_at.is_some() {
+        return Err(Error::ModelDeleted(id));
+    }
+    Ok(())
+}
+
+/// Apply one score, already passed by `check_score`, to a model, returning
+/// its new status and any transition.
+///
+/// Metrics are left for the caller to recompute once all scores are applied.
+fn apply_score(
+    strategy: &dyn StatusStrategy,
+    config: &PluginConfig,
+    models: &mut HashMap<Uuid, Arc<MonitoredModel>>,
+    id: Uuid,
+    score: f64,
+    now: DateTime<Utc>,
+) -> Result<(ModelStatus, Option<Transition>), Error> {
+    let upstream_critical = dependencies::dependency_critical(models, id);
+    let model = Arc::make_mut(models.get_mut(&id).ok_or(Error::ModelNotFound(id))?);
+    
+    model.performance_score = match config.decay_alpha {
+        // The first sample seeds the average rather than blending with the placeholder
+        // score, as does a non-finite previous value rather than propagating it
+        Some(alpha) if !model.history.is_empty() && model.performance_score.is_finite() => {
+            alpha * score + (1.0 - alpha) * model.performance_score
         }
+        _ => score,
+    };
+    model.last_check = now;
+    model.missed_checks = 0;
+    model.push_sample(now, score, config.history_capacity);
+    let derived = strategy.derive(model.status_score(config), config, model);
+    model.consecutive_healthy = match derived {
+        ModelStatus::Healthy => model.consecutive_healthy.saturating_add(1),
+        _ => 0,
+    };
+    // Leaving Degraded/Critical takes `recovery_confirmations` healthy readings in a row
+    let unconfirmed = derived == ModelStatus::Healthy
+        && matches!(model.status, ModelStatus::Degraded | ModelStatus::Critical)
+        && model.consecutive_healthy < config.recovery_confirmations;
+    let own_status = if unconfirmed { model.status.clone() } else { derived };
+    // A model still filling its caches may look degraded but is never critical
+    let in_warmup = model.in_warmup(config, now);
+    let own_status = if in_warmup && own_status == ModelStatus::Critical { ModelStatus::Degraded } else { own_status };
+    model.dependency_degraded = upstream_critical && own_status == ModelStatus::Healthy;
+    let new_status = if model.dependency_degraded { ModelStatus::Degraded } else { own_status };
+    let old_status = model.set_status(new_status.clone(), now);
+    if old_status == new_status {
+        return Ok((new_status, None));
     }
+    
+    let remediate = (new_status == ModelStatus::Critical
+        && config.auto_remediation
+        && config.tier.allows(Feature::AutoRemediation))
+    .then(|| model.clone());
+    let transition = Transition {
+        alert: Alert::new(model, old_status),
+        remediate,
+        in_warmup,
+    };
+    Ok((new_status, Some(transition)))
+}
+
+/// Log a status change: recoveries at info, degradation at warn, critical or offline at error
+fn log_transition(alert: &Alert) {
+    let (id, name, from, to) = (alert.model_id, alert.name.as_str(), &alert.old_status, &alert.new_status);
+    match to {
+        ModelStatus::Critical | ModelStatus::Offline => {
+            error!(model_id = %id, model_name = %name, "Model changed status: {:?} -> {:?}", from, to)
+        }
+        ModelStatus::Degraded => {
+            warn!(model_id = %id, model_name = %name, "Model changed status: {:?} -> {:?}", from, to)
+        }
+        ModelStatus::Healthy | ModelStatus::Unknown => {
+            info!(model_id = %id, model_name = %name, "Model changed status: {:?} -> {:?}", from, to)
+        }
+    }
+}
+
+/// Widen an `f32` score to the `f64` nearest its shortest decimal form, so
+/// 0.7f32 becomes 0.7 rather than 0.699999988
+pub(crate) fn widen_score(score: f32) -> f64 {
+    score.to_string().parse().unwrap_or(f64::from(score))
+}
+
+/// Rebuild `system_metrics` from the monitored models so it can never drift
+fn recompute_metrics(state: &mut PluginState) {
+    state.system_metrics = SystemMetrics::from_models(state.monitored_models.values().map(Arc::as_ref));
 }
 
 impl ModelPerformanceMonitoringPlugin {
-    pub async fn new() -> Result<Self> {
+    /// Create the plugin with config from `PluginConfig::load`, restoring
+    /// the models saved at its `state_path` if that file exists
+    pub async fn new() -> Result<Self, Error> {
+        Self::with_config(PluginConfig::load()?).await
+    }
+    
+    /// Create the plugin with `config`, restoring the models saved at its
+    /// `state_path` if that file exists. `config` replaces the saved one.
+    pub async fn with_config(config: PluginConfig) -> Result<Self, Error> {
+        // The clock `with_state` installs; a mock can only be set afterwards
+        let saved = match &config.state_path {
+            Some(path) if path.exists() => {
+                persistence::read_state(path, StateFormat::from_path(path), SystemClock.now()).await?
+            }
+            _ => PluginState::default(),
+        };
+        Self::with_state(PluginState { config, ..saved })
+    }
+    
+    /// Create the plugin, restoring monitored models from `path` if it
+    /// exists, otherwise starting empty with config from `PluginConfig::load`
+    pub async fn with_state_file(path: &Path) -> Result<Self, Error> {
+        let state = if path.exists() {
+            persistence::read_state(path, StateFormat::from_path(path), SystemClock.now()).await?
+        } else {
+            PluginState {
+                config: PluginConfig::load()?,
+                ..PluginState::default()
+            }
+        };
+        Self::with_state(state)
+    }
+    
+    fn with_state(mut state: PluginState) -> Result<Self, Error> {
+        recompute_metrics(&mut state);
+        state.external_ids = state
+            .monitored_models
+            .values()
+            .filter_map(|m| Some((m.external_id.clone()?, m.id)))
+            .collect::<HashMap<_, _>>()
+            .into();
+        
         let info = PluginInfo {
             id: "adios.model-performance-monitoring".to_string(),
             name: "AdiOS Model Performance Monitoring".to_string(),
@@ -108,11 +765,29 @@ impl ModelPerformanceMonitoringPlugin {
             category: "enterprise".to_string(),
         };
         
-        let state = RwLock::new(PluginState::default());
-        
         Ok(Self {
             info,
-            state,
+            state: Arc::new(state_cell::StateCell::new(state)),
+            health_check: Arc::new(RwLock::new(None)),
+            shutdown: Arc::new(Notify::new()),
+            shutdown_requested: Arc::new(Notify::new()),
+            stopping: Arc::new(watch::Sender::new(false)),
+            config_changed: Arc::new(Notify::new()),
+            http_client: reqwest::Client::new(),
+            remediation_handler: Arc::new(RwLock::new(Arc::new(NoopRemediation))),
+            metrics_source: Arc::new(RwLock::new(None)),
+            status_strategy: Arc::new(RwLock::new(Arc::new(ThresholdStrategy))),
+            schedule: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            last_push: Arc::new(std::sync::Mutex::new(None)),
+            monitor_restarts: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            remediating: Arc::new(std::sync::Mutex::new(HashSet::new())),
+            last_alerts: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            alert_limiter: Arc::new(std::sync::Mutex::new(alerting::AlertRateLimiter::new())),
+            failed_deliveries: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            events: broadcast::channel(events::EVENT_CAPACITY).0,
+            timeseries: Arc::new(RwLock::new(TimeSeriesStore::new())),
+            clock: Arc::new(std::sync::RwLock::new(Arc::new(SystemClock))),
+            audit_log: Arc::new(std::sync::Mutex::new(None)),
         })
     }
     
@@ -165,112 +840,1393 @@ impl ModelPerformanceMonitoringPlugin {
         ]
     }
     
-    /// Run the plugin's main loop
-    pub async fn run(&self) -> Result<()> {
-        info!("Starting AdiOS Model Performance Monitoring Plugin v{}", self.version());
-        
-        // Display plugin information
-        info!("Plugin: {}", self.name());
-        info!("Description: {}", self.description());
+    /// Look up an advertised pricing tier by name, ignoring case
+    pub fn tier_for(&self, name: &str) -> Option<PricingTier> {
+        self.pricing_tiers().into_iter().find(|tier| tier.name.eq_ignore_ascii_case(name))
+    }
+    
+    /// Whether the configured tier includes `feature`
+    pub fn tier_allows(&self, feature: Feature) -> bool {
+        self.current_config().tier.allows(feature)
+    }
+    
+    /// Model cap of the configured tier, `None` for unlimited
+    pub fn model_capacity(&self) -> Option<usize> {
+        self.current_config().tier.max_models()
+    }
+    
+    /// Register a new model for monitoring and return its id.
+    ///
+    /// `model_type` also accepts plain strings, which are mapped onto the
+    /// matching `ModelType` variant.
+    pub async fn add_model(&self, name: String, model_type: impl Into<ModelType>) -> Result<Uuid, Error> {
+        self.add_model_with_external_id(name, model_type, None).await
+    }
+    
+    /// Like [`add_model`](Self::add_model), but idempotent on `external_id`:
+    /// if a monitored model was already added with the same key, its id is
+    /// returned and nothing is created, so callers can safely retry.
+    #[tracing::instrument(name = "add_model", skip_all, fields(model_name = %name, model_id = tracing::field::Empty))]
+    pub async fn add_model_with_external_id(
+        &self,
+        name: String,
+        model_type: impl Into<ModelType>,
+        external_id: Option<String>,
+    ) -> Result<Uuid, Error> {
+        if name.trim().is_empty() {
+            return Err(Error::EmptyName);
+        }
         
-        // Display pricing tiers
-        info!("Available pricing tiers:");
-        for tier in self.pricing_tiers() {
-            info!("  {} - ${:.2}/month", tier.name, tier.price as f32 / 100.0);
-            for feature in &tier.features {
-                info!("    • {}", feature);
+        let mut state = self.state.write().await;
+        if let Some(&existing) = external_id.as_ref().and_then(|key| state.external_ids.get(key)) {
+            Span::current().record("model_id", tracing::field::display(existing));
+            info!("Model with this external id is already monitored");
+            return Ok(existing);
+        }
+        if state.monitored_models.values().any(|m| m.name == name) {
+            return Err(Error::DuplicateName(name));
+        }
+        let tier = state.config.tier;
+        if let Some(limit) = tier.max_models() {
+            if state.live_models() >= limit {
+                return Err(Error::CapacityExceeded { tier, limit });
             }
         }
         
-        // Start the UI
-        info!("Starting model performance monitoring interface...");
-        self.run_ui().await?;
+        let model = MonitoredModel {
+            external_id,
+            ..MonitoredModel::new(name, model_type.into(), self.now())
+        };
+        let id = model.id;
+        Span::current().record("model_id", tracing::field::display(id));
+        if let Some(key) = &model.external_id {
+            Arc::make_mut(&mut state.external_ids).insert(key.clone(), id);
+        }
+        state.monitored_models.insert(id, Arc::new(model));
+        recompute_metrics(&mut state);
+        drop(state);
         
-        Ok(())
+        info!("Monitoring model");
+        
+        // A new model is due right away, ahead of whatever the loop is sleeping until
+        self.config_changed.notify_waiters();
+        self.emit(StateEvent::ModelAdded(id));
+        self.emit(StateEvent::MetricsUpdated);
+        Ok(id)
     }
     
-    async fn run_ui(&self) -> Result<()> {
-        println!("=== AdiOS Model Performance Monitoring Plugin ===");
-        println!("Enterprise model performance monitoring and auto-improvement");
-        println!();
-        println!("Available commands:");
-        println!("  1. Add model to monitoring");
-        println!("  2. View model health dashboard");
-        println!("  3. Show pricing tiers");
-        println!("  4. Exit");
-        println!();
-        
-        println!("Key Features:");
-        println!("  • Real-time performance monitoring");
-        println!("  • Automated remediation");
-        println!("  • Performance analytics");
-        println!("  • Custom alerting");
-        
-        println!();
-        println!("Pricing Tiers:");
-        for tier in self.pricing_tiers() {
-            println!("  • {} - ${:.2}/month", tier.name, tier.price as f32 / 100.0);
+    /// Stop monitoring a model, archive it and return its final state
+    #[tracing::instrument(skip_all, fields(model_id = %id, model_name = tracing::field::Empty))]
+    pub async fn remove_model(&self, id: Uuid) -> Result<MonitoredModel, Error> {
+        let mut state = self.state.write().await;
+        let model = match state.monitored_models.remove(&id) {
+            Some(model) => Arc::unwrap_or_clone(model),
+            None => return Err(Error::ModelNotFound(id)),
+        };
+        if let Some(key) = &model.external_id {
+            Arc::make_mut(&mut state.external_ids).remove(key);
         }
         
-        println!();
-        println!("Plugin is ready for model monitoring!");
+        for other in state.monitored_models.values_mut().filter(|m| m.depends_on.contains(&id)) {
+            Arc::make_mut(other).depends_on.retain(|dep| *dep != id);
+        }
+        let config = state.config.clone();
+        let now = self.now();
+        let transitions = dependencies::propagate_dependencies(&mut state.monitored_models, &config, now);
+        recompute_metrics(&mut state);
+        let capacity = state.config.archive_capacity;
+        archive::archive_model(&mut state.archived_models, model.clone(), now, capacity);
+        drop(state);
+        self.timeseries.write().await.remove(id);
+        self.last_alerts.lock().unwrap().retain(|(model_id, _), _| *model_id != id);
         
-        Ok(())
+        Span::current().record("model_name", tracing::field::display(&model.name));
+        info!("Stopped monitoring model");
+        self.emit(StateEvent::ModelRemoved(id));
+        self.finish_transitions(&config, transitions);
+        Ok(model)
     }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PricingTier {
-    pub name: String,
-    pub price: u32, // in cents
-    pub features: Vec<String>,
-}
-
-// Entry point for the plugin
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize logging
-    tracing_subscriber::fmt()
-        .init();
     
-    // Create and run plugin
-    let plugin = ModelPerformanceMonitoringPlugin::new().await?;
-    plugin.run().await?;
+    /// Give a model a new name, which must be non-empty and not used by
+    /// any other model
+    pub async fn rename_model(&self, id: Uuid, new_name: String) -> Result<(), Error> {
+        if new_name.trim().is_empty() {
+            return Err(Error::EmptyName);
+        }
+        
+        let mut state = self.state.write().await;
+        if state.monitored_models.values().any(|m| m.name == new_name && m.id != id) {
+            return Err(Error::DuplicateName(new_name));
+        }
+        let model = state.model_mut(id)?;
+        let old_name = std::mem::replace(&mut model.name, new_name);
+        info!(model_id = %id, "Renamed model '{}' to '{}'", old_name, model.name);
+        drop(state);
+        
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(())
+    }
     
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn test_plugin_initialization() {
-        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+    /// Clear a model's history and status so it is re-baselined, e.g. after
+    /// retraining, while keeping its id, name, tags and metadata
+    pub async fn reset_model(&self, id: Uuid) -> Result<(), Error> {
+        let mut state = self.state.write().await;
+        let model = state.model_mut(id)?;
+        let now = self.now();
+        let reset = AuditRecord {
+            timestamp: now,
+            model_id: id,
+            from: model.status.clone(),
+            to: ModelStatus::Unknown,
+            score: model.performance_score,
+        };
+        model.history.clear();
+        model.performance_score = 1.0;
+        model.last_check = now;
+        let old_status = std::mem::replace(&mut model.status, ModelStatus::Unknown);
+        model.status_since = now;
+        model.status_durations.clear();
+        model.consecutive_healthy = 0;
+        model.missed_checks = 0;
+        recompute_metrics(&mut state);
+        let config = state.config.clone();
+        drop(state);
+        self.timeseries.write().await.remove(id);
         
-        // Test basic functionality
-        assert_eq!(plugin.name(), "AdiOS Model Performance Monitoring");
-        assert_eq!(plugin.version(), env!("CARGO_PKG_VERSION"));
-        assert!(!plugin.description().is_empty());
+        self.last_alerts.lock().unwrap().retain(|(model_id, _), _| *model_id != id);
+        info!(model_id = %id, "Reset model history and status");
+        if old_status != ModelStatus::Unknown {
+            self.audit(&config, [reset]);
+            self.emit(StateEvent::StatusChanged {
+                id,
+                from: old_status,
+                to: ModelStatus::Unknown,
+            });
+        }
+        self.emit(StateEvent::ModelUpdated(id));
+        self.emit(StateEvent::MetricsUpdated);
+        Ok(())
     }
-
-    #[tokio::test]
-    async fn test_pricing_tiers() {
-        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+    
+    /// Record a new performance score for a model and return its updated status
+    #[tracing::instrument(skip_all, fields(model_id = %id, model_name = tracing::field::Empty, score = score))]
+    pub async fn record_performance(&self, id: Uuid, score: f64) -> Result<ModelStatus, Error> {
+        let strategy = self.status_strategy.read().await.clone();
+        let mut guard = self.state.write().await;
+        // Checked before taking mutable access, so a rejected score publishes nothing
+        check_score(&guard.monitored_models, id, score)?;
+        let state = &mut *guard;
+        let now = self.now();
+        let (status, transition) = apply_score(&*strategy, &state.config, &mut state.monitored_models, id, score, now)?;
+        let mut transitions: Vec<Transition> = transition.into_iter().collect();
+        transitions.extend(dependencies::propagate_dependencies(&mut state.monitored_models, &state.config, now));
+        recompute_metrics(state);
+        let config = state.config.clone();
+        let model = &state.monitored_models[&id];
+        let recorded_at = model.last_check;
+        Span::current().record("model_name", tracing::field::display(&model.name));
+        drop(guard);
         
-        let tiers = plugin.pricing_tiers();
-        assert_eq!(tiers.len(), 3);
+        self.timeseries.write().await.record(id, recorded_at, score, config.series_retention());
+        self.finish_transitions(&config, transitions);
+        Ok(status)
+    }
+    
+    /// Record many scores under a single write lock.
+    ///
+    /// Each sample gets its own result, so an unknown id or out-of-range score
+    /// fails only that sample. Metrics are recomputed once after the batch.
+    pub async fn record_performance_batch(&self, samples: Vec<(Uuid, f64)>) -> Vec<Result<ModelStatus, Error>> {
+        let strategy = self.status_strategy.read().await.clone();
+        let mut guard = self.state.write().await;
+        let mut transitions = Vec::new();
+        let mut recorded = Vec::new();
+        let now = self.now();
+        let results = samples
+            .into_iter()
+            .map(|(id, score)| {
+                check_score(&guard.monitored_models, id, score)?;
+                let state = &mut *guard;
+                let (status, transition) = apply_score(&*strategy, &state.config, &mut state.monitored_models, id, score, now)?;
+                transitions.extend(transition);
+                recorded.push((id, state.monitored_models[&id].last_check, score));
+                Ok(status)
+            })
+            .collect();
+        if recorded.is_empty() {
+            return results;
+        }
+        let state = &mut *guard;
+        transitions.extend(dependencies::propagate_dependencies(&mut state.monitored_models, &state.config, now));
+        recompute_metrics(state);
+        let config = state.config.clone();
+        drop(guard);
         
-        // Starter tier
-        assert_eq!(tiers[0].name, "Starter");
-        assert_eq!(tiers[0].price, 150000); // $1,500
+        let mut timeseries = self.timeseries.write().await;
+        for (id, at, score) in recorded {
+            timeseries.record(id, at, score, config.series_retention());
+        }
+        drop(timeseries);
         
-        // Professional tier
-        assert_eq!(tiers[1].name, "Professional");
-        assert_eq!(tiers[1].price, 750000); // $7,500
+        self.finish_transitions(&config, transitions);
+        results
+    }
+    
+    /// Publish events and start alerts/remediation for committed status changes
+    fn finish_transitions(&self, config: &PluginConfig, transitions: Vec<Transition>) {
+        self.audit(config, transitions.iter().map(|t| AuditRecord::from(&t.alert)));
+        for transition in &transitions {
+            log_transition(&transition.alert);
+            self.emit(StateEvent::StatusChanged {
+                id: transition.alert.model_id,
+                from: transition.alert.old_status.clone(),
+                to: transition.alert.new_status.clone(),
+            });
+        }
+        self.emit(StateEvent::MetricsUpdated);
         
-        // Enterprise tier
-        assert_eq!(tiers[2].name, "Enterprise");
-        assert_eq!(tiers[2].price, 3000000); // $30,000
+        let mut alerts = Vec::with_capacity(transitions.len());
+        for transition in transitions {
+            if transition.in_warmup {
+                debug!(model_id = %transition.alert.model_id, "Suppressing alert during warmup");
+            } else {
+                alerts.push(transition.alert);
+            }
+            if let Some(model) = transition.remediate {
+                self.trigger_remediation(config, model);
+            }
+        }
+        self.dispatch_alerts(config, alerts);
+    }
+    
+    /// Set or clear a model's own performance threshold.
+    ///
+    /// Like config updates, this takes effect from the model's next check.
+    pub async fn set_model_threshold(&self, id: Uuid, threshold: Option<f64>) -> Result<(), Error> {
+        if let Some(threshold) = threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(Error::InvalidThreshold(threshold));
+            }
+        }
+        
+        let mut state = self.state.write().await;
+        match state.monitored_models.get_mut(&id) {
+            Some(model) => Arc::make_mut(model).threshold_override = threshold,
+            None => return Err(Error::ModelNotFound(id)),
+        }
+        drop(state);
+        
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(())
+    }
+    
+    /// Pause checking of a model, e.g. during maintenance of its backing
+    /// service. Until resumed, the monitoring loop skips it and recorded
+    /// scores are rejected, so it changes status and alerts on nothing.
+    pub async fn pause_model(&self, id: Uuid) -> Result<(), Error> {
+        self.set_paused(id, true).await
+    }
+    
+    /// Resume checking of a paused model from its last known score and status
+    pub async fn resume_model(&self, id: Uuid) -> Result<(), Error> {
+        self.set_paused(id, false).await
+    }
+    
+    /// Whether a model is still within `warmup_minutes` of being added
+    pub async fn model_in_warmup(&self, id: Uuid) -> Result<bool, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(model.in_warmup(&state.config, self.now()))
+    }
+    
+    async fn set_paused(&self, id: Uuid, paused: bool) -> Result<(), Error> {
+        let mut state = self.state.write().await;
+        match state.monitored_models.get_mut(&id) {
+            Some(model) => Arc::make_mut(model).paused = paused,
+            None => return Err(Error::ModelNotFound(id)),
+        }
+        drop(state);
+        
+        info!(model_id = %id, "{} model", if paused { "Paused" } else { "Resumed" });
+        // Paused models don't count towards the loop's tick period
+        self.config_changed.notify_waiters();
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(())
+    }
+    
+    /// Set how much a model counts towards `system_health_score`.
+    ///
+    /// Must be finite and non-negative; 0 excludes the model.
+    pub async fn set_model_importance(&self, id: Uuid, importance: f32) -> Result<(), Error> {
+        if !importance.is_finite() || importance < 0.0 {
+            return Err(Error::InvalidImportance(importance));
+        }
+        
+        let mut state = self.state.write().await;
+        match state.monitored_models.get_mut(&id) {
+            Some(model) => Arc::make_mut(model).importance = importance,
+            None => return Err(Error::ModelNotFound(id)),
+        }
+        drop(state);
+        
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(())
+    }
+    
+    /// Tag a model; returns `false` if it already had the tag
+    pub async fn add_tag(&self, id: Uuid, tag: &str) -> Result<bool, Error> {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(Error::EmptyTag);
+        }
+        
+        let mut state = self.state.write().await;
+        let model = state.model_mut(id)?;
+        let added = model.tags.insert(tag.to_string());
+        drop(state);
+        
+        if added {
+            self.emit(StateEvent::ModelUpdated(id));
+        }
+        Ok(added)
+    }
+    
+    /// Remove a tag from a model; returns `false` if it didn't have the tag
+    pub async fn remove_tag(&self, id: Uuid, tag: &str) -> Result<bool, Error> {
+        let mut state = self.state.write().await;
+        let model = state.model_mut(id)?;
+        let removed = model.tags.remove(tag.trim());
+        drop(state);
+        
+        if removed {
+            self.emit(StateEvent::ModelUpdated(id));
+        }
+        Ok(removed)
+    }
+    
+    /// Set a metadata entry on a model, returning the previous value.
+    ///
+    /// Keys must be non-empty; keys and values longer than the configured
+    /// `max_metadata_key_len`/`max_metadata_value_len` are rejected.
+    pub async fn set_metadata(&self, id: Uuid, key: &str, value: &str) -> Result<Option<String>, Error> {
+        let mut state = self.state.write().await;
+        let config = &state.config;
+        if key.is_empty() {
+            return Err(Error::InvalidMetadata("key must not be empty".to_string()));
+        }
+        if key.len() > config.max_metadata_key_len {
+            return Err(Error::InvalidMetadata(format!(
+                "key is {} bytes, the limit is {}",
+                key.len(),
+                config.max_metadata_key_len
+            )));
+        }
+        if value.len() > config.max_metadata_value_len {
+            return Err(Error::InvalidMetadata(format!(
+                "value for '{}' is {} bytes, the limit is {}",
+                key,
+                value.len(),
+                config.max_metadata_value_len
+            )));
+        }
+        
+        let model = state.model_mut(id)?;
+        let previous = model.metadata.insert(key.to_string(), value.to_string());
+        drop(state);
+        
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(previous)
+    }
+    
+    /// Look up a metadata entry on a model
+    pub async fn get_metadata(&self, id: Uuid, key: &str) -> Result<Option<String>, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(model.metadata.get(key).cloned())
+    }
+    
+    /// Remove a metadata entry from a model, returning its value if it was set
+    pub async fn remove_metadata(&self, id: Uuid, key: &str) -> Result<Option<String>, Error> {
+        let mut state = self.state.write().await;
+        let model = state.model_mut(id)?;
+        let removed = model.metadata.remove(key);
+        drop(state);
+        
+        if removed.is_some() {
+            self.emit(StateEvent::ModelUpdated(id));
+        }
+        Ok(removed)
+    }
+    
+    /// Replace the running configuration.
+    ///
+    /// The new config is validated first; on error the current config is left
+    /// untouched. A running monitoring loop picks up a changed check interval
+    /// straight away. Threshold changes only apply to scores recorded from now
+    /// on: existing models keep their status until they are checked again.
+    pub async fn update_config(&self, new_config: PluginConfig) -> Result<(), Error> {
+        new_config.validate()?;
+        
+        self.state.write().await.set_config(new_config);
+        self.config_changed.notify_waiters();
+        
+        info!("Plugin configuration updated");
+        self.emit(StateEvent::ConfigUpdated);
+        Ok(())
+    }
+    
+    /// Deep copy of the state as of the last completed write, taken without
+    /// locking.
+    ///
+    /// Models, metrics and config in the snapshot are mutually consistent.
+    /// It is a copy, so changes made to the plugin afterwards are not
+    /// reflected in it.
+    pub async fn snapshot(&self) -> PluginState {
+        PluginState::clone(&self.state.load())
+    }
+    
+    /// Current state of a single model
+    pub async fn get_model(&self, id: Uuid) -> Option<MonitoredModel> {
+        self.state.load().monitored_models.get(&id).map(Arc::as_ref).cloned()
+    }
+    
+    /// Current state of the model with the given name
+    pub async fn get_model_by_name(&self, name: &str) -> Option<MonitoredModel> {
+        let state = self.state.load();
+        state.monitored_models.values().find(|m| m.name == name).map(Arc::as_ref).cloned()
+    }
+    
+    /// Recorded score samples for a model, oldest first
+    pub async fn model_history(&self, id: Uuid) -> Result<Vec<(DateTime<Utc>, f64)>, Error> {
+        let state = self.state.load();
+        match state.monitored_models.get(&id) {
+            Some(model) => Ok(model.history.iter().copied().collect()),
+            None => Err(Error::ModelNotFound(id)),
+        }
+    }
+    
+    /// Recorded score samples taken at or after `since`, oldest first.
+    ///
+    /// History is kept in time order, so the cutoff is found by binary search.
+    pub async fn model_history_since(&self, id: Uuid, since: DateTime<Utc>) -> Result<Vec<(DateTime<Utc>, f64)>, Error> {
+        let state = self.state.load();
+        let history = &state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?.history;
+        let start = history.partition_point(|(at, _)| *at < since);
+        Ok(history.range(start..).copied().collect())
+    }
+    
+    /// Score used for status derivation, see `MonitoredModel::status_score`
+    pub async fn smoothed_score(&self, id: Uuid) -> Result<f64, Error> {
+        let state = self.state.load();
+        match state.monitored_models.get(&id) {
+            Some(model) => Ok(model.status_score(&state.config)),
+            None => Err(Error::ModelNotFound(id)),
+        }
+    }
+    
+    /// Snapshot of monitored models, worst performers first, optionally filtered by status
+    pub async fn list_models(&self, filter: Option<ModelStatus>) -> Vec<MonitoredModel> {
+        let state = self.state.load();
+        let mut models: Vec<MonitoredModel> = state
+            .monitored_models
+            .values()
+            .map(Arc::as_ref)
+            .filter(|m| filter.as_ref().is_none_or(|status| &m.status == status))
+            .cloned()
+            .collect();
+        drop(state);
+        
+        models.sort_by(|a, b| a.performance_score.total_cmp(&b.performance_score));
+        models
+    }
+    
+    /// Snapshot of models carrying `tag`, worst performers first
+    pub async fn list_by_tag(&self, tag: &str) -> Vec<MonitoredModel> {
+        let state = self.state.load();
+        let mut models: Vec<MonitoredModel> = state
+            .monitored_models
+            .values()
+            .map(Arc::as_ref)
+            .filter(|m| m.tags.contains(tag))
+            .cloned()
+            .collect();
+        drop(state);
+        
+        models.sort_by(|a, b| a.performance_score.total_cmp(&b.performance_score));
+        models
+    }
+    
+    /// Run the plugin until a shutdown signal is received
+    pub async fn run(&self) -> Result<()> {
+        info!("Starting AdiOS Model Performance Monitoring Plugin v{}", self.version());
+        
+        // Display plugin information
+        info!("Plugin: {}", self.name());
+        info!("Description: {}", self.description());
+        
+        // Display pricing tiers
+        info!("Available pricing tiers:");
+        for tier in self.pricing_tiers() {
+            info!("  {} - ${:.2}/month", tier.name, tier.price as f32 / 100.0);
+            for feature in &tier.features {
+                info!("    • {}", feature);
+            }
+        }
+        
+        let monitoring = self.start_monitoring();
+        
+        // Start the UI; a signal still shuts down while it waits for input
+        info!("Starting model performance monitoring interface...");
+        tokio::select! {
+            result = self.run_ui() => {
+                result?;
+                self.wait_for_shutdown().await;
+            }
+            _ = self.wait_for_shutdown() => {}
+        }
+        
+        self.stopping.send_replace(true);
+        self.stop_monitoring();
+        if let Err(e) = monitoring.await {
+            warn!("Monitoring loop ended abnormally: {}", e);
+        }
+        self.persist_on_shutdown().await;
+        
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PricingTier {
+    pub name: String,
+    pub price: u32, // in cents
+    pub features: Vec<String>,
+}
+
+/// Subscription tier the plugin is licensed for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PricingTierName {
+    Starter,
+    Professional,
+    /// Default so existing deployments keep monitoring an unlimited fleet
+    #[default]
+    Enterprise,
+}
+
+impl PricingTierName {
+    /// Maximum number of monitored models, `None` for unlimited
+    pub fn max_models(self) -> Option<usize> {
+        match self {
+            PricingTierName::Starter => Some(10),
+            PricingTierName::Professional => Some(100),
+            PricingTierName::Enterprise => None,
+        }
+    }
+    
+    /// Whether `feature` is included in this tier; higher tiers include
+    /// everything from the tiers below them
+    pub fn allows(self, feature: Feature) -> bool {
+        self >= feature.minimum_tier()
+    }
+}
+
+/// Capabilities advertised in `pricing_tiers`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Feature {
+    BasicMonitoring,
+    PerformanceDashboards,
+    EmailAlerts,
+    AdvancedAnalytics,
+    AutoRemediation,
+    CustomMetrics,
+    PrioritySupport,
+    AiOptimization,
+    CustomRemediationWorkflows,
+    DedicatedSupport,
+    OnPremisesDeployment,
+}
+
+impl Feature {
+    /// Cheapest tier that includes this feature
+    pub fn minimum_tier(self) -> PricingTierName {
+        match self {
+            Feature::BasicMonitoring | Feature::PerformanceDashboards | Feature::EmailAlerts => {
+                PricingTierName::Starter
+            }
+            Feature::AdvancedAnalytics
+            | Feature::AutoRemediation
+            | Feature::CustomMetrics
+            | Feature::PrioritySupport => PricingTierName::Professional,
+            Feature::AiOptimization
+            | Feature::CustomRemediationWorkflows
+            | Feature::DedicatedSupport
+            | Feature::OnPremisesDeployment => PricingTierName::Enterprise,
+        }
+    }
+}
+
+// Entry point for the plugin
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize logging
+    tracing_subscriber::fmt()
+        .init();
+    
+    // Create and run plugin
+    let plugin = ModelPerformanceMonitoringPlugin::new().await?;
+    plugin.run().await?;
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_plugin_initialization() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        // Test basic functionality
+        assert_eq!(plugin.name(), "AdiOS Model Performance Monitoring");
+        assert_eq!(plugin.version(), env!("CARGO_PKG_VERSION"));
+        assert!(!plugin.description().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pricing_tiers() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let tiers = plugin.pricing_tiers();
+        assert_eq!(tiers.len(), 3);
+        
+        // Starter tier
+        assert_eq!(tiers[0].name, "Starter");
+        assert_eq!(tiers[0].price, 150000); // $1,500
+        
+        // Professional tier
+        assert_eq!(tiers[1].name, "Professional");
+        assert_eq!(tiers[1].price, 750000); // $7,500
+        
+        // Enterprise tier
+        assert_eq!(tiers[2].name, "Enterprise");
+        assert_eq!(tiers[2].price, 3000000); // $30,000
+    }
+
+    #[tokio::test]
+    async fn test_add_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let id = plugin.add_model("fraud-detector".to_string(), ModelType::Classification).await.unwrap();
+        
+        let state = plugin.state.read().await;
+        let model = &state.monitored_models[&id];
+        assert_eq!(model.name, "fraud-detector");
+        assert_eq!(model.model_type, ModelType::Classification);
+        assert_eq!(model.status, ModelStatus::Unknown);
+        assert_eq!(model.performance_score, 1.0);
+        assert_eq!(state.system_metrics.total_models, 1);
+        assert_eq!(state.system_metrics.healthy_models, 0);
+        assert_eq!(state.system_metrics.unknown_models, 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_model_rejects_empty_name() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        assert!(plugin.add_model("".to_string(), "classification".to_string()).await.is_err());
+        assert!(plugin.add_model("   ".to_string(), "classification".to_string()).await.is_err());
+        assert!(plugin.state.read().await.monitored_models.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_model_rejects_duplicate_name() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        plugin.add_model("churn".to_string(), "classification".to_string()).await.unwrap();
+        assert!(plugin.add_model("churn".to_string(), "regression".to_string()).await.is_err());
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.monitored_models.len(), 1);
+        assert_eq!(state.system_metrics.total_models, 1);
+    }
+
+    #[tokio::test]
+    async fn test_remove_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let keep = plugin.add_model("keep".to_string(), "regression".to_string()).await.unwrap();
+        let drop = plugin.add_model("drop".to_string(), "regression".to_string()).await.unwrap();
+        plugin.record_performance(keep, 0.9).await.unwrap();
+        plugin.record_performance(drop, 0.7).await.unwrap();
+        
+        let removed = plugin.remove_model(drop).await.unwrap();
+        assert_eq!(removed.name, "drop");
+        
+        let state = plugin.state.read().await;
+        assert!(state.monitored_models.contains_key(&keep));
+        assert_eq!(state.system_metrics.total_models, 1);
+        assert_eq!(state.system_metrics.healthy_models, 1);
+        assert_eq!(state.system_metrics.degraded_models, 0);
+        assert!((state.system_metrics.average_performance - 0.9).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_remove_unknown_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        assert!(plugin.remove_model(Uuid::new_v4()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let good = plugin.add_model("good".to_string(), "llm".to_string()).await.unwrap();
+        let bad = plugin.add_model("bad".to_string(), "llm".to_string()).await.unwrap();
+        {
+            let mut state = plugin.state.write().await;
+            let model = state.model_mut(bad).unwrap();
+            model.performance_score = 0.4;
+            model.status = ModelStatus::Critical;
+        }
+        
+        let all = plugin.list_models(None).await;
+        assert_eq!(all.iter().map(|m| m.id).collect::<Vec<_>>(), vec![bad, good]);
+        
+        let critical = plugin.list_models(Some(ModelStatus::Critical)).await;
+        assert_eq!(critical.len(), 1);
+        assert_eq!(critical[0].id, bad);
+        
+        assert!(plugin.list_models(Some(ModelStatus::Offline)).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_record_performance_status_bands() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), "regression".to_string()).await.unwrap();
+        
+        // Default threshold is 0.85
+        assert_eq!(plugin.record_performance(id, 0.90).await.unwrap(), ModelStatus::Healthy);
+        assert_eq!(plugin.record_performance(id, 0.85).await.unwrap(), ModelStatus::Healthy);
+        assert_eq!(plugin.record_performance(id, 0.80).await.unwrap(), ModelStatus::Degraded);
+        assert_eq!(plugin.record_performance(id, 0.50).await.unwrap(), ModelStatus::Critical);
+        
+        let state = plugin.state.read().await;
+        let model = &state.monitored_models[&id];
+        assert_eq!(model.performance_score, 0.50);
+        assert_eq!(model.status, ModelStatus::Critical);
+        assert_eq!(state.system_metrics.healthy_models, 0);
+        assert_eq!(state.system_metrics.degraded_models, 1);
+    }
+
+    #[tokio::test]
+    async fn test_record_performance_rejects_invalid_input() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), "regression".to_string()).await.unwrap();
+        
+        assert!(plugin.record_performance(id, 1.5).await.is_err());
+        assert!(plugin.record_performance(id, -0.1).await.is_err());
+        assert!(plugin.record_performance(Uuid::new_v4(), 0.9).await.is_err());
+        assert_eq!(plugin.state.read().await.monitored_models[&id].performance_score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_track_model_changes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        assert_eq!(plugin.state.read().await.system_metrics.average_performance, 0.0);
+        
+        let a = plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        let b = plugin.add_model("b".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(a, 0.9).await.unwrap();
+        plugin.record_performance(b, 0.5).await.unwrap();
+        
+        {
+            let state = plugin.state.read().await;
+            assert_eq!(state.system_metrics.total_models, 2);
+            assert_eq!(state.system_metrics.healthy_models, 1);
+            assert_eq!(state.system_metrics.degraded_models, 1);
+            assert!((state.system_metrics.average_performance - 0.7).abs() < 1e-6);
+        }
+        
+        plugin.remove_model(a).await.unwrap();
+        plugin.remove_model(b).await.unwrap();
+        let state = plugin.state.read().await;
+        assert_eq!(state.system_metrics.total_models, 0);
+        assert_eq!(state.system_metrics.average_performance, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_first_record_leaves_unknown() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("fresh".to_string(), "llm".to_string()).await.unwrap();
+        
+        assert_eq!(plugin.record_performance(id, 0.95).await.unwrap(), ModelStatus::Healthy);
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.system_metrics.unknown_models, 0);
+        assert_eq!(state.system_metrics.healthy_models, 1);
+    }
+
+    #[test]
+    fn test_unknown_status_serde_round_trip() {
+        let json = serde_json::to_string(&ModelStatus::Unknown).unwrap();
+        assert_eq!(json, "\"Unknown\"");
+        assert_eq!(serde_json::from_str::<ModelStatus>(&json).unwrap(), ModelStatus::Unknown);
+    }
+
+    #[test]
+    fn test_status_ordering() {
+        assert!(ModelStatus::Critical > ModelStatus::Degraded);
+        assert!(ModelStatus::Offline > ModelStatus::Critical);
+        assert!(ModelStatus::Unknown < ModelStatus::Healthy);
+        assert!(ModelStatus::Degraded >= ModelStatus::Degraded);
+        
+        let mut statuses = vec![
+            ModelStatus::Critical,
+            ModelStatus::Healthy,
+            ModelStatus::Offline,
+            ModelStatus::Unknown,
+            ModelStatus::Degraded,
+        ];
+        statuses.sort();
+        assert_eq!(statuses, ModelStatus::ALL);
+        assert!(statuses.windows(2).all(|pair| pair[0].severity() < pair[1].severity()));
+        assert_eq!(statuses.iter().max(), Some(&ModelStatus::Offline));
+    }
+
+    #[tokio::test]
+    async fn test_update_config() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let config = PluginConfig {
+            performance_threshold: 0.7,
+            ..PluginConfig::default()
+        };
+        plugin.update_config(config).await.unwrap();
+        assert_eq!(plugin.state.read().await.config.performance_threshold, 0.7);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_config_update_keeps_old_config() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let config = PluginConfig {
+            check_interval_minutes: 0,
+            performance_threshold: 0.5,
+            ..PluginConfig::default()
+        };
+        assert!(plugin.update_config(config).await.is_err());
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.config.check_interval_minutes, 5);
+        assert_eq!(state.config.performance_threshold, 0.85);
+    }
+
+    #[tokio::test]
+    async fn test_history_is_capped() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            history_capacity: 3,
+            drift_recent_window: 1,
+            drift_baseline_window: 2,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("capped".to_string(), "llm".to_string()).await.unwrap();
+        
+        for score in [0.1, 0.2, 0.3, 0.4, 0.5] {
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        
+        let scores: Vec<f64> = plugin.model_history(id).await.unwrap().into_iter().map(|(_, s)| s).collect();
+        assert_eq!(scores, vec![0.3, 0.4, 0.5]);
+        assert!(plugin.model_history(Uuid::new_v4()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_smoothing_prevents_flapping() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let noisy = plugin.add_model("noisy".to_string(), "llm".to_string()).await.unwrap();
+        
+        // Unsmoothed, every reading flips the status
+        let raw: Vec<ModelStatus> = record_scores(&plugin, noisy, &[0.95, 0.55, 0.95, 0.55]).await;
+        assert_eq!(raw, vec![ModelStatus::Healthy, ModelStatus::Critical, ModelStatus::Healthy, ModelStatus::Critical]);
+        
+        plugin.update_config(PluginConfig {
+            smoothing_window: 4,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        
+        // The window averages to 0.75, which stays inside the degraded band
+        let smoothed = record_scores(&plugin, noisy, &[0.95, 0.55, 0.95, 0.55]).await;
+        assert!(smoothed.iter().all(|s| *s == ModelStatus::Degraded));
+        assert!((plugin.smoothed_score(noisy).await.unwrap() - 0.75).abs() < 1e-6);
+    }
+
+    async fn record_scores(plugin: &ModelPerformanceMonitoringPlugin, id: Uuid, scores: &[f64]) -> Vec<ModelStatus> {
+        let mut statuses = Vec::new();
+        for &score in scores {
+            statuses.push(plugin.record_performance(id, score).await.unwrap());
+        }
+        statuses
+    }
+
+    #[test]
+    fn test_model_type_from_string() {
+        assert_eq!(ModelType::from("LLM".to_string()), ModelType::LLM);
+        assert_eq!(ModelType::from("embedding"), ModelType::Embedding);
+        assert_eq!(ModelType::from("ranking"), ModelType::Other("ranking".to_string()));
+    }
+
+    #[test]
+    fn test_model_type_serde() {
+        assert_eq!(serde_json::to_string(&ModelType::Regression).unwrap(), "\"regression\"");
+        assert_eq!(serde_json::from_str::<ModelType>("\"classification\"").unwrap(), ModelType::Classification);
+        
+        // Free-form values from older state files are preserved
+        let other: ModelType = serde_json::from_str("\"recommender\"").unwrap();
+        assert_eq!(other, ModelType::Other("recommender".to_string()));
+        assert_eq!(serde_json::to_string(&other).unwrap(), "\"recommender\"");
+    }
+
+    #[tokio::test]
+    async fn test_threshold_override() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let strict = plugin.add_model("strict".to_string(), ModelType::Classification).await.unwrap();
+        let lenient = plugin.add_model("lenient".to_string(), ModelType::Classification).await.unwrap();
+        plugin.set_model_threshold(strict, Some(0.95)).await.unwrap();
+        plugin.set_model_threshold(lenient, Some(0.6)).await.unwrap();
+        
+        assert_eq!(plugin.record_performance(strict, 0.8).await.unwrap(), ModelStatus::Degraded);
+        assert_eq!(plugin.record_performance(lenient, 0.8).await.unwrap(), ModelStatus::Healthy);
+        
+        // Clearing the override falls back to the global 0.85 threshold
+        plugin.set_model_threshold(lenient, None).await.unwrap();
+        assert_eq!(plugin.record_performance(lenient, 0.8).await.unwrap(), ModelStatus::Degraded);
+    }
+
+    #[tokio::test]
+    async fn test_threshold_override_validation() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("m".to_string(), ModelType::Regression).await.unwrap();
+        
+        assert!(plugin.set_model_threshold(id, Some(1.2)).await.is_err());
+        assert!(plugin.set_model_threshold(id, Some(-0.5)).await.is_err());
+        assert!(plugin.set_model_threshold(Uuid::new_v4(), Some(0.5)).await.is_err());
+        assert_eq!(plugin.state.read().await.monitored_models[&id].threshold_override, None);
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_matchable() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("m".to_string(), ModelType::LLM).await.unwrap();
+        let missing = Uuid::new_v4();
+        
+        assert!(matches!(plugin.add_model("".to_string(), ModelType::LLM).await, Err(Error::EmptyName)));
+        assert!(matches!(
+            plugin.add_model("m".to_string(), ModelType::LLM).await,
+            Err(Error::DuplicateName(name)) if name == "m"
+        ));
+        assert!(matches!(plugin.record_performance(id, 2.0).await, Err(Error::InvalidScore(s)) if s == 2.0));
+        assert!(matches!(plugin.remove_model(missing).await, Err(Error::ModelNotFound(m)) if m == missing));
+        assert!(matches!(
+            plugin.update_config(PluginConfig { check_interval_minutes: 0, ..PluginConfig::default() }).await,
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("lookup".to_string(), ModelType::Regression).await.unwrap();
+        
+        let by_id = plugin.get_model(id).await.unwrap();
+        assert_eq!(by_id.name, "lookup");
+        
+        let by_name = plugin.get_model_by_name("lookup").await.unwrap();
+        assert_eq!(by_name.id, id);
+        
+        assert!(plugin.get_model(Uuid::new_v4()).await.is_none());
+        assert!(plugin.get_model_by_name("missing").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_model_is_idempotent_on_external_id() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let key = Some("cp-42".to_string());
+        
+        let first = plugin.add_model_with_external_id("ranker".to_string(), ModelType::LLM, key.clone()).await.unwrap();
+        let retry = plugin.add_model_with_external_id("ranker".to_string(), ModelType::LLM, key.clone()).await.unwrap();
+        assert_eq!(retry, first);
+        assert_eq!(plugin.list_models(None).await.len(), 1);
+        assert_eq!(plugin.get_model(first).await.unwrap().external_id, key);
+        
+        // Without a key a second add is still a duplicate name
+        assert!(matches!(
+            plugin.add_model("ranker".to_string(), ModelType::LLM).await,
+            Err(Error::DuplicateName(_))
+        ));
+        
+        // Removing the model frees its key
+        plugin.remove_model(first).await.unwrap();
+        let readded = plugin.add_model_with_external_id("ranker".to_string(), ModelType::LLM, key).await.unwrap();
+        assert_ne!(readded, first);
+    }
+
+    #[tokio::test]
+    async fn test_tier_model_capacity() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        assert_eq!(plugin.model_capacity(), None);
+        
+        plugin.update_config(PluginConfig {
+            tier: PricingTierName::Starter,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        assert_eq!(plugin.model_capacity(), Some(10));
+        
+        for i in 0..10 {
+            plugin.add_model(format!("model-{}", i), ModelType::LLM).await.unwrap();
+        }
+        assert!(matches!(
+            plugin.add_model("one-too-many".to_string(), ModelType::LLM).await,
+            Err(Error::CapacityExceeded { tier: PricingTierName::Starter, limit: 10 })
+        ));
+        assert_eq!(plugin.state.read().await.monitored_models.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_tier_for() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        assert_eq!(plugin.tier_for("professional").unwrap().price, 750000);
+        assert!(plugin.tier_for("Platinum").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_record_performance_batch() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), ModelType::LLM).await.unwrap();
+        let b = plugin.add_model("b".to_string(), ModelType::LLM).await.unwrap();
+        let unknown = Uuid::new_v4();
+        
+        let results = plugin
+            .record_performance_batch(vec![(a, 0.9), (unknown, 0.9), (b, 1.5), (b, 0.7)])
+            .await;
+        
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &ModelStatus::Healthy);
+        assert!(matches!(results[1], Err(Error::ModelNotFound(id)) if id == unknown));
+        assert!(matches!(results[2], Err(Error::InvalidScore(_))));
+        assert_eq!(results[3].as_ref().unwrap(), &ModelStatus::Degraded);
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.monitored_models[&b].history.len(), 1);
+        assert_eq!(state.system_metrics.healthy_models, 1);
+        assert_eq!(state.system_metrics.degraded_models, 1);
+        assert!((state.system_metrics.average_performance - 0.8).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_ewma_scoring() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            decay_alpha: Some(0.3),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("ewma".to_string(), ModelType::LLM).await.unwrap();
+        
+        // s1 = 0.8
+        // s2 = 0.3 * 0.4 + 0.7 * 0.8   = 0.68
+        // s3 = 0.3 * 1.0 + 0.7 * 0.68  = 0.776
+        let expected = [(0.8, 0.8), (0.4, 0.68), (1.0, 0.776)];
+        for (sample, smoothed) in expected {
+            plugin.record_performance(id, sample).await.unwrap();
+            let score = plugin.get_model(id).await.unwrap().performance_score;
+            assert!((score - smoothed).abs() < 1e-6, "expected {}, got {}", smoothed, score);
+        }
+        
+        // History keeps the raw samples
+        let raw: Vec<f64> = plugin.model_history(id).await.unwrap().into_iter().map(|(_, s)| s).collect();
+        assert_eq!(raw, vec![0.8, 0.4, 1.0]);
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Degraded);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_metrics_stay_consistent_under_concurrency() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let tasks: Vec<_> = (0..64)
+            .map(|i| {
+                let plugin = plugin.clone();
+                tokio::spawn(async move {
+                    let id = plugin.add_model(format!("model-{}", i), ModelType::LLM).await.unwrap();
+                    for step in 0..10 {
+                        plugin.record_performance(id, ((i + step) % 10) as f64 / 10.0).await.unwrap();
+                    }
+                    if i % 2 == 0 {
+                        plugin.remove_model(id).await.unwrap();
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        
+        let state = plugin.state.write().await;
+        assert!(state.metrics_consistent());
+        assert_eq!(state.system_metrics.total_models, 32);
+        assert_eq!(state.monitored_models.len(), 32);
+    }
+
+    #[tokio::test]
+    async fn test_tags() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), ModelType::LLM).await.unwrap();
+        let b = plugin.add_model("b".to_string(), ModelType::LLM).await.unwrap();
+        plugin.add_model("c".to_string(), ModelType::LLM).await.unwrap();
+        
+        assert!(plugin.add_tag(a, "production").await.unwrap());
+        assert!(!plugin.add_tag(a, "production").await.unwrap());
+        plugin.add_tag(a, "team-x").await.unwrap();
+        plugin.add_tag(b, " production ").await.unwrap();
+        plugin.record_performance(a, 0.9).await.unwrap();
+        plugin.record_performance(b, 0.4).await.unwrap();
+        
+        let ids: Vec<Uuid> = plugin.list_by_tag("production").await.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![b, a]);
+        assert!(plugin.list_by_tag("staging").await.is_empty());
+        
+        assert!(plugin.remove_tag(b, "production").await.unwrap());
+        assert!(!plugin.remove_tag(b, "production").await.unwrap());
+        assert_eq!(plugin.list_by_tag("production").await.len(), 1);
+        
+        assert!(matches!(plugin.add_tag(a, "  ").await, Err(Error::EmptyTag)));
+        assert!(matches!(plugin.add_tag(Uuid::new_v4(), "x").await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_tags_serialize_sorted() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("tagged".to_string(), ModelType::LLM).await.unwrap();
+        for tag in ["zeta", "alpha", "mid"] {
+            plugin.add_tag(id, tag).await.unwrap();
+        }
+        
+        let model = plugin.get_model(id).await.unwrap();
+        let json = serde_json::to_value(&model).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["alpha", "mid", "zeta"]));
+    }
+
+    #[tokio::test]
+    async fn test_operations_are_traced() {
+        let (_guard, logs) = crate::test_support::capture_logs();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("traced".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        plugin.record_performance(id, 0.7).await.unwrap();
+        plugin.record_performance(id, 0.1).await.unwrap();
+        plugin.remove_model(id).await.unwrap();
+        
+        let output = logs.contents();
+        let span = format!("model_name=traced model_id={}", id);
+        assert!(output.contains(&format!("add_model{{{}}}: adios_model_performance_monitoring: Monitoring model", span)), "{}", output);
+        
+        let lines: Vec<&str> = output.lines().filter(|l| l.contains("changed status")).collect();
+        assert_eq!(lines.len(), 3, "{}", output);
+        assert!(lines[0].contains(" INFO ") && lines[0].contains("Unknown -> Healthy"));
+        assert!(lines[1].contains(" WARN ") && lines[1].contains("Healthy -> Degraded"));
+        assert!(lines[2].contains("ERROR ") && lines[2].contains("Degraded -> Critical"));
+        let span = format!("record_performance{{model_id={} score=", id);
+        assert!(lines.iter().all(|l| l.contains(&span) && l.contains("model_name=traced}")), "{}", output);
+        
+        assert!(output.contains("remove_model{"));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_is_detached() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("snap".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        let snapshot = plugin.snapshot().await;
+        assert!(snapshot.metrics_consistent());
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        plugin.add_model("later".to_string(), ModelType::LLM).await.unwrap();
+        plugin.update_config(PluginConfig {
+            check_interval_minutes: 60,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        
+        assert_eq!(snapshot.monitored_models.len(), 1);
+        assert_eq!(snapshot.monitored_models[&id].status, ModelStatus::Healthy);
+        assert_eq!(snapshot.monitored_models[&id].history.len(), 1);
+        assert_eq!(snapshot.system_metrics.healthy_models, 1);
+        assert_eq!(snapshot.config.check_interval_minutes, 5);
+    }
+
+    #[tokio::test]
+    async fn test_metadata() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("annotated".to_string(), ModelType::LLM).await.unwrap();
+        
+        assert_eq!(plugin.set_metadata(id, "owner", "alice").await.unwrap(), None);
+        assert_eq!(plugin.get_metadata(id, "owner").await.unwrap().as_deref(), Some("alice"));
+        
+        // Overwriting returns the old value
+        assert_eq!(plugin.set_metadata(id, "owner", "bob").await.unwrap().as_deref(), Some("alice"));
+        assert_eq!(plugin.get_metadata(id, "owner").await.unwrap().as_deref(), Some("bob"));
+        
+        assert_eq!(plugin.remove_metadata(id, "owner").await.unwrap().as_deref(), Some("bob"));
+        assert_eq!(plugin.get_metadata(id, "owner").await.unwrap(), None);
+        assert_eq!(plugin.remove_metadata(id, "owner").await.unwrap(), None);
+        assert!(matches!(plugin.get_metadata(Uuid::new_v4(), "owner").await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_metadata_size_limits() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("annotated".to_string(), ModelType::LLM).await.unwrap();
+        
+        for (key, value) in [("", "x".to_string()), (&"k".repeat(129)[..], "x".to_string()), ("dataset", "v".repeat(1025))] {
+            let err = plugin.set_metadata(id, key, &value).await.unwrap_err();
+            assert!(matches!(err, Error::InvalidMetadata(_)));
+        }
+        assert!(plugin.set_metadata(id, &"k".repeat(128), &"v".repeat(1024)).await.is_ok());
+        assert!(plugin.get_model(id).await.unwrap().metadata.len() == 1);
+    }
+
+    #[tokio::test]
+    async fn test_model_history_since() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("recent".to_string(), ModelType::LLM).await.unwrap();
+        let base = Utc::now() - chrono::Duration::hours(1);
+        let at = |minutes| base + chrono::Duration::minutes(minutes);
+        {
+            let mut state = plugin.state.write().await;
+            let model = state.model_mut(id).unwrap();
+            for (minutes, score) in [(0, 0.5), (10, 0.6), (20, 0.7), (30, 0.8)] {
+                model.push_sample(at(minutes), score, 100);
+            }
+        }
+        
+        let samples = plugin.model_history_since(id, at(10)).await.unwrap();
+        assert_eq!(samples, vec![(at(10), 0.6), (at(20), 0.7), (at(30), 0.8)]);
+        assert_eq!(plugin.model_history_since(id, at(25)).await.unwrap(), vec![(at(30), 0.8)]);
+        assert_eq!(plugin.model_history_since(id, at(-5)).await.unwrap().len(), 4);
+        assert!(plugin.model_history_since(id, at(31)).await.unwrap().is_empty());
+        assert!(matches!(plugin.model_history_since(Uuid::new_v4(), at(0)).await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reset_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("retrained".to_string(), ModelType::LLM).await.unwrap();
+        plugin.set_metadata(id, "version", "1").await.unwrap();
+        record_scores(&plugin, id, &[0.9, 0.5, 0.2]).await;
+        let (from, to) = (Utc::now() - chrono::Duration::hours(1), Utc::now() + chrono::Duration::hours(1));
+        assert_eq!(plugin.query_scores(id, from, to).await.unwrap().len(), 3);
+        
+        plugin.reset_model(id).await.unwrap();
+        
+        let model = plugin.get_model(id).await.unwrap();
+        assert!(model.history.is_empty());
+        assert!(plugin.query_scores(id, from, to).await.unwrap().is_empty());
+        assert_eq!(model.status, ModelStatus::Unknown);
+        assert_eq!(model.performance_score, 1.0);
+        assert!(model.status_durations.is_empty());
+        assert_eq!(model.metadata["version"], "1");
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.system_metrics.unknown_models, 1);
+        assert_eq!(state.system_metrics.degraded_models, 0);
+        drop(state);
+        
+        assert!(matches!(plugin.reset_model(Uuid::new_v4()).await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_non_finite_scores_leave_state_untouched() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            decay_alpha: Some(0.5),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("guarded".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        let before = serde_json::to_value(plugin.snapshot().await).unwrap();
+        
+        for score in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(plugin.record_performance(id, score).await, Err(Error::InvalidScore(_))));
+            let results = plugin.record_performance_batch(vec![(id, score)]).await;
+            assert!(matches!(results[0], Err(Error::InvalidScore(_))));
+        }
+        
+        assert_eq!(serde_json::to_value(plugin.snapshot().await).unwrap(), before);
+        assert_eq!(plugin.query_scores(id, Utc::now() - chrono::Duration::hours(1), Utc::now()).await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_tier_feature_gating() {
+        use PricingTierName::*;
+        
+        assert!(Starter.allows(Feature::EmailAlerts));
+        assert!(!Starter.allows(Feature::AutoRemediation));
+        assert!(Professional.allows(Feature::AutoRemediation));
+        assert!(Professional.allows(Feature::BasicMonitoring));
+        assert!(!Professional.allows(Feature::OnPremisesDeployment));
+        assert!(Enterprise.allows(Feature::CustomMetrics));
+        assert!(Enterprise.allows(Feature::OnPremisesDeployment));
+    }
+
+    #[tokio::test]
+    async fn test_rename_model() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("churn".to_string(), "classification".to_string()).await.unwrap();
+        plugin.add_model("fraud".to_string(), "classification".to_string()).await.unwrap();
+        
+        plugin.rename_model(id, "churn-v2".to_string()).await.unwrap();
+        
+        assert!(plugin.get_model_by_name("churn").await.is_none());
+        assert_eq!(plugin.get_model_by_name("churn-v2").await.unwrap().id, id);
+        assert_eq!(plugin.get_model(id).await.unwrap().name, "churn-v2");
+        
+        assert!(matches!(
+            plugin.rename_model(id, "fraud".to_string()).await,
+            Err(Error::DuplicateName(name)) if name == "fraud"
+        ));
+        assert!(matches!(plugin.rename_model(id, " ".to_string()).await, Err(Error::EmptyName)));
+        assert!(matches!(
+            plugin.rename_model(Uuid::new_v4(), "other".to_string()).await,
+            Err(Error::ModelNotFound(_))
+        ));
+        assert_eq!(plugin.get_model(id).await.unwrap().name, "churn-v2");
+    }
+
+    #[tokio::test]
+    async fn test_recovery_requires_consecutive_healthy_readings() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            recovery_confirmations: 3,
+            auto_remediation: false,
+            ..Default::default()
+        }).await.unwrap();
+        let id = plugin.add_model("flapping".to_string(), "llm".to_string()).await.unwrap();
+        
+        // A single good reading among bad ones doesn't clear Critical
+        for score in [0.2, 0.95, 0.2, 0.95, 0.95, 0.2] {
+            assert_eq!(plugin.record_performance(id, score).await.unwrap(), ModelStatus::Critical);
+        }
+        
+        assert_eq!(plugin.record_performance(id, 0.95).await.unwrap(), ModelStatus::Critical);
+        assert_eq!(plugin.record_performance(id, 0.95).await.unwrap(), ModelStatus::Critical);
+        assert_eq!(plugin.record_performance(id, 0.95).await.unwrap(), ModelStatus::Healthy);
+        
+        // Getting worse is never held back
+        assert_eq!(plugin.record_performance(id, 0.8).await.unwrap(), ModelStatus::Degraded);
     }
 }
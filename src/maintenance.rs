@@ -0,0 +1,123 @@
+//! Maintenance windows
+//!
+//! Recurring daily time-of-day ranges, in UTC, during which webhook alerts
+//! are suppressed fleet-wide for planned work such as nightly batch jobs.
+//! Checks and status transitions carry on as usual; only alerts are held.
+
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::PluginConfig;
+
+/// A daily window from `start` up to (not including) `end`, e.g.
+/// `{ start = "02:00", end = "03:00" }`. A window whose `end` is before its
+/// `start` wraps past midnight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MaintenanceWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl MaintenanceWindow {
+    /// Whether `now` falls inside the window
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        let time = now.time();
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl PluginConfig {
+    /// Whether `now` falls inside any of the `maintenance_windows`
+    pub fn in_maintenance(&self, now: DateTime<Utc>) -> bool {
+        self.maintenance_windows.iter().any(|window| window.contains(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock_http_server;
+    use crate::ModelPerformanceMonitoringPlugin;
+    use chrono::{Duration, TimeZone};
+    use std::time::Duration as StdDuration;
+
+    fn window(start: &str, end: &str) -> MaintenanceWindow {
+        MaintenanceWindow {
+            start: start.parse().unwrap(),
+            end: end.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_window_contains() {
+        let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 1, h, m, 0).unwrap();
+        let nightly = window("02:00", "03:00");
+        assert!(nightly.contains(at(2, 0)));
+        assert!(nightly.contains(at(2, 59)));
+        assert!(!nightly.contains(at(3, 0)));
+        assert!(!nightly.contains(at(1, 59)));
+        
+        let overnight = window("23:00", "01:00");
+        assert!(overnight.contains(at(23, 30)));
+        assert!(overnight.contains(at(0, 30)));
+        assert!(!overnight.contains(at(1, 0)));
+        assert!(!overnight.contains(at(12, 0)));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_window() {
+        let config = PluginConfig {
+            maintenance_windows: vec![window("02:00", "02:00")],
+            ..PluginConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    /// Window of `offset` to `offset + 1h` from the current time of day
+    fn window_from_now(offset: Duration) -> MaintenanceWindow {
+        let start = Utc::now() + offset;
+        let end = start + Duration::hours(1);
+        MaintenanceWindow {
+            start: start.time(),
+            end: end.time(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alerts_suppressed_during_maintenance() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            maintenance_windows: vec![window_from_now(-Duration::minutes(30))],
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("batch-scored".to_string(), "llm".to_string()).await.unwrap();
+        
+        // The transition is still recorded
+        assert_eq!(plugin.record_performance(id, 0.1).await.unwrap(), crate::ModelStatus::Critical);
+        tokio::time::sleep(StdDuration::from_millis(50)).await;
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_alerts_sent_outside_maintenance() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            maintenance_windows: vec![window_from_now(Duration::hours(2))],
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("batch-scored".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        
+        assert_eq!(requests.recv().await.unwrap().request_line, "POST /hook HTTP/1.1");
+    }
+}
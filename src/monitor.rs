@@ -0,0 +1,834 @@
+//! Background monitoring loop
+//!
+//! Periodically walks every monitored model that is due for a check, pulls
+//! its score from the metrics source and hands it to the registered
+//! health-check callback. Each model is checked every `check_interval_minutes`
+//! unless it has its own `check_interval_override`. Checks can be spread out
+//! with a per-check jitter drawn from an RNG seeded by `check_jitter_seed`,
+//! so a fixed seed gives a reproducible schedule.
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tokio::time::Instant;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    dependencies, recompute_metrics, Alert, Error, ModelPerformanceMonitoringPlugin, ModelStatus, MonitoredModel, StateEvent,
+    Transition,
+};
+
+/// Delay before the first restart of a panicked monitoring loop, doubled on each further restart
+const RESTART_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound on the delay between restarts
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Callback invoked for each monitored model on every check tick
+pub type HealthCheck = Arc<dyn Fn(&MonitoredModel) + Send + Sync>;
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Register the callback the monitoring loop runs against each model
+    pub async fn set_health_check<F>(&self, check: F)
+    where
+        F: Fn(&MonitoredModel) + Send + Sync + 'static,
+    {
+        *self.health_check.write().await = Some(Arc::new(check));
+    }
+    
+    /// Spawn the monitoring loop.
+    ///
+    /// Each model is checked as soon as the loop starts, then every
+    /// `check_interval_minutes` (or its own override) plus a random jitter of
+    /// up to `check_jitter_seconds`, until `stop_monitoring` is called.
+    /// Awaiting the returned handle after stopping waits for the in-flight
+    /// check cycle to finish.
+    pub fn start_monitoring(&self) -> JoinHandle<()> {
+        let plugin = self.clone();
+        tokio::spawn(async move { plugin.supervise_monitoring().await })
+    }
+    
+    /// Number of times the monitoring loop has been restarted after a panic
+    pub fn monitor_restarts(&self) -> u64 {
+        self.monitor_restarts.load(Ordering::Relaxed)
+    }
+    
+    /// Run the monitoring loop in its own task, restarting it with
+    /// exponential backoff if it panics, up to `monitor_max_restarts` times
+    async fn supervise_monitoring(&self) {
+        let mut restarts = 0;
+        loop {
+            let plugin = self.clone();
+            let panic = match tokio::spawn(async move { plugin.monitoring_loop().await }).await {
+                Ok(()) => return,
+                Err(e) if e.is_panic() => e.into_panic(),
+                Err(_) => return,
+            };
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            
+            let max_restarts = self.current_config().monitor_max_restarts;
+            if restarts >= max_restarts {
+                error!("Monitoring loop panicked: {}; giving up after {} restarts", message, restarts);
+                return;
+            }
+            restarts += 1;
+            self.monitor_restarts.fetch_add(1, Ordering::Relaxed);
+            let backoff = (RESTART_BACKOFF * 2u32.saturating_pow(restarts - 1)).min(MAX_RESTART_BACKOFF);
+            error!(
+                "Monitoring loop panicked: {}; restarting in {:?} ({}/{})",
+                message, backoff, restarts, max_restarts
+            );
+            
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = self.shutdown.notified() => return,
+            }
+        }
+    }
+    
+    /// Set or clear a model's own check interval in minutes.
+    ///
+    /// A running monitoring loop picks the new cadence up on its next wakeup.
+    pub async fn set_check_interval(&self, id: Uuid, minutes: Option<u32>) -> Result<(), Error> {
+        if minutes == Some(0) {
+            return Err(Error::InvalidCheckInterval);
+        }
+        
+        let mut state = self.state.write().await;
+        match state.monitored_models.get_mut(&id) {
+            Some(model) => Arc::make_mut(model).check_interval_override = minutes,
+            None => return Err(Error::ModelNotFound(id)),
+        }
+        drop(state);
+        
+        self.config_changed.notify_waiters();
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(())
+    }
+    
+    /// Active models owned by this shard whose `last_check` is more than `max_age` ago, oldest first
+    pub async fn stale_models(&self, max_age: Duration) -> Vec<Uuid> {
+        let max_age = chrono::Duration::from_std(max_age).unwrap_or(chrono::Duration::MAX);
+        let cutoff = self.now().checked_sub_signed(max_age).unwrap_or(chrono::DateTime::<Utc>::MIN_UTC);
+        
+        let state = self.state.load();
+        let mut stale: Vec<&MonitoredModel> = state
+            .monitored_models
+            .values()
+            .map(Arc::as_ref)
+            .filter(|m| m.is_active() && state.config.owns_model(m.id) && m.last_check < cutoff)
+            .collect();
+        stale.sort_by(|a, b| a.last_check.cmp(&b.last_check).then(a.id.cmp(&b.id)));
+        stale.into_iter().map(|m| m.id).collect()
+    }
+    
+    /// Ask a running monitoring loop to exit after its current cycle
+    pub fn stop_monitoring(&self) {
+        self.shutdown.notify_one();
+    }
+    
+    /// Scheduled time of each model's next check, for models the running
+    /// monitoring loop has checked at least once
+    pub async fn next_check_times(&self) -> HashMap<Uuid, Instant> {
+        let state = self.state.load();
+        let schedule = self.schedule.lock().unwrap();
+        schedule
+            .iter()
+            .filter_map(|(id, slot)| {
+                let model = state.monitored_models.get(id)?;
+                Some((*id, slot.due(check_period(model.check_interval_minutes(&state.config)))))
+            })
+            .collect()
+    }
+    
+    async fn monitoring_loop(&self) {
+        let (minutes, seed) = {
+            let config = self.current_config();
+            (config.check_interval_minutes, config.check_jitter_seed)
+        };
+        let mut rng = StdRng::seed_from_u64(seed.unwrap_or_else(rand::random));
+        self.schedule.lock().unwrap().clear();
+        info!("Monitoring loop started (every {} min)", minutes);
+        
+        loop {
+            // Registered before the wake time is computed, so a change made meanwhile isn't missed
+            let changed = self.config_changed.notified();
+            let stopped = self.shutdown.notified();
+            tokio::pin!(changed, stopped);
+            changed.as_mut().enable();
+            stopped.as_mut().enable();
+            
+            // Recomputed after every wakeup, as a config update or new model may change it
+            let wake = self.next_wake().await;
+            let push_at = self.next_push().await;
+            tokio::select! {
+                _ = tokio::time::sleep_until(wake) => {
+                    self.run_due_checks(&mut rng).await;
+                    self.purge_deleted().await;
+                    self.push_metrics_if_due().await;
+                }
+                _ = tokio::time::sleep_until(push_at.unwrap_or(wake)), if push_at.is_some() => self.push_metrics_if_due().await,
+                _ = changed => {}
+                _ = stopped => break,
+            }
+        }
+        
+        info!("Monitoring loop stopped");
+    }
+    
+    /// Earliest time any active model of this shard is due; models never checked are due
+    /// now. With no models, wakes after `check_interval_minutes` to look again.
+    async fn next_wake(&self) -> Instant {
+        let now = Instant::now();
+        let state = self.state.load();
+        let mut schedule = self.schedule.lock().unwrap();
+        schedule.retain(|id, _| state.monitored_models.contains_key(id));
+        
+        state
+            .monitored_models
+            .values()
+            .filter(|m| m.is_active() && state.config.owns_model(m.id))
+            .map(|m| {
+                let interval = check_period(m.check_interval_minutes(&state.config));
+                schedule.get(&m.id).map_or(now, |slot| slot.due(interval))
+            })
+            .min()
+            .unwrap_or_else(|| now + check_period(state.config.check_interval_minutes.max(1)))
+    }
+    
+    /// Check every due model, then reschedule each with a fresh jitter
+    async fn run_due_checks(&self, rng: &mut StdRng) {
+        let now = Instant::now();
+        let schedule = self.schedule.lock().unwrap().clone();
+        let due = |model: &MonitoredModel, interval: Duration| schedule.get(&model.id).is_none_or(|slot| slot.due(interval) <= now);
+        let checked = self.run_checks_where(due).await;
+        
+        let max_jitter = Duration::from_secs(u64::from(self.current_config().check_jitter_seconds));
+        let mut schedule = self.schedule.lock().unwrap();
+        for id in checked {
+            // The slot is anchored to the unjittered due time so jitter never accumulates
+            let base = schedule.get(&id).map_or(now, |slot| now.checked_sub(slot.jitter).unwrap_or(now));
+            let jitter = if max_jitter.is_zero() { Duration::ZERO } else { rng.random_range(Duration::ZERO..=max_jitter) };
+            schedule.insert(id, ScheduleSlot { base, jitter });
+        }
+    }
+    
+    /// Run one check cycle against every active model
+    #[cfg(test)]
+    pub(crate) async fn run_checks(&self) {
+        self.run_checks_where(|_, _| true).await;
+    }
+    
+    /// Check one model right away, outside its regular cadence, and return
+    /// its resulting status.
+    ///
+    /// With a metrics source the score is fetched and recorded now; with
+    /// push-based scores the model is instead marked due and the monitoring
+    /// loop woken to check it. The health check then runs against
+    /// the model. The schedule of other models is left alone.
+    pub async fn force_check(&self, id: Uuid) -> Result<ModelStatus, Error> {
+        let (model, timeout) = {
+            let state = self.state.load();
+            let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+            if model.paused {
+                return Err(Error::ModelPaused(id));
+            }
+            if model.deleted_at.is_some() {
+                return Err(Error::ModelDeleted(id));
+            }
+            (MonitoredModel::clone(model), state.config.operation_timeout())
+        };
+        
+        let source = self.metrics_source.read().await.clone();
+        let model = match source {
+            Some(source) => {
+                let fetch = async {
+                    source.refresh().await?;
+                    source.fetch(&model).await
+                };
+                let score = match tokio::time::timeout(timeout, fetch).await {
+                    Ok(Ok(score)) => score,
+                    Ok(Err(e)) => return Err(Error::MetricsSource { id, reason: format!("{:#}", e) }),
+                    Err(_) => return Err(Error::MetricsSource { id, reason: format!("fetch timed out after {:?}", timeout) }),
+                };
+                self.record_performance(id, score).await?;
+                self.get_model(id).await.ok_or(Error::ModelNotFound(id))?
+            }
+            None => {
+                self.schedule.lock().unwrap().remove(&id);
+                self.config_changed.notify_waiters();
+                model
+            }
+        };
+        info!(model_id = %id, "Forced check of '{}': {:?}", model.name, model.status);
+        
+        if let Some(check) = self.health_check.read().await.clone() {
+            check(&model);
+        }
+        Ok(model.status)
+    }
+    
+    /// Run one check cycle against the active models of this shard `due` accepts given
+    /// their check interval: pull scores from the metrics source, if any,
+    /// then run the health check. Models whose fetch fails, or which have
+    /// not pushed a score within their interval, miss the check. Returns
+    /// the ids of the models visited.
+    async fn run_checks_where(&self, due: impl Fn(&MonitoredModel, Duration) -> bool) -> Vec<Uuid> {
+        let source = self.metrics_source.read().await.clone();
+        let check = self.health_check.read().await.clone();
+        
+        // Check a snapshot so the callback never runs while holding the state lock,
+        // least recently checked first so stuck models aren't starved
+        let (mut models, stale, silent, timeout) = {
+            let state = self.state.load();
+            let now = self.now();
+            let (mut stale, mut silent) = (0, Vec::new());
+            let mut models = Vec::new();
+            for model in state.monitored_models.values().filter(|m| m.is_active() && state.config.owns_model(m.id)) {
+                let interval = check_period(model.check_interval_minutes(&state.config));
+                let overdue = chrono::Duration::from_std(interval).is_ok_and(|i| now - model.last_check > i);
+                stale += usize::from(overdue);
+                if due(model, interval) {
+                    if overdue {
+                        silent.push(model.id);
+                    }
+                    models.push(model.clone());
+                }
+            }
+            (models, stale, silent, state.config.operation_timeout())
+        };
+        models.sort_by_key(|m| m.last_check);
+        // Push-based models are expected to report by themselves each interval
+        if source.is_none() {
+            self.record_missed_checks(&silent).await;
+        }
+        if source.is_none() && check.is_none() {
+            return models.into_iter().map(|m| m.id).collect();
+        }
+        
+        if stale > 0 {
+            warn!("{} models have not been checked within their check interval", stale);
+        }
+        debug!("Running health checks for {} models", models.len());
+        
+        if let Some(source) = source {
+            match tokio::time::timeout(timeout, source.refresh()).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => warn!("Metrics source refresh failed: {:#}", e),
+                Err(_) => warn!("Metrics source refresh timed out after {:?}", timeout),
+            }
+            
+            // One failing or hung model must not stop the rest of the cycle
+            let mut fetched = Vec::with_capacity(models.len());
+            let mut missed = Vec::new();
+            for model in &models {
+                match tokio::time::timeout(timeout, source.fetch(model)).await {
+                    Ok(Ok(score)) => fetched.push((model, score)),
+                    Ok(Err(e)) => {
+                        warn!(model_id = %model.id, "Skipping '{}' this cycle: {:#}", model.name, e);
+                        missed.push(model.id);
+                    }
+                    Err(_) => {
+                        warn!(model_id = %model.id, "Skipping '{}' this cycle: fetch timed out after {:?}", model.name, timeout);
+                        missed.push(model.id);
+                    }
+                }
+            }
+            self.record_missed_checks(&missed).await;
+            
+            // Recorded as one batch so the cycle's transitions are alerted on together
+            let samples = fetched.iter().map(|(model, score)| (model.id, *score)).collect();
+            let results = self.record_performance_batch(samples).await;
+            for ((model, _), result) in fetched.into_iter().zip(results) {
+                if let Err(e) = result {
+                    warn!(model_id = %model.id, "Skipping '{}' this cycle: {}", model.name, e);
+                }
+            }
+        }
+        if let Some(check) = check {
+            for model in &models {
+                check(model);
+            }
+        }
+        models.into_iter().map(|m| m.id).collect()
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Count a missed check against each model in `ids`, marking those that
+    /// reach `offline_after_missed_checks` in a row `Offline`. Only a
+    /// recorded score brings them back.
+    async fn record_missed_checks(&self, ids: &[Uuid]) {
+        if ids.is_empty() {
+            return;
+        }
+        
+        let now = self.now();
+        let mut guard = self.state.write().await;
+        let state = &mut *guard;
+        let limit = state.config.offline_after_missed_checks;
+        let mut transitions = Vec::new();
+        for id in ids {
+            let Some(model) = state.monitored_models.get_mut(id).map(Arc::make_mut) else {
+                continue;
+            };
+            model.missed_checks = model.missed_checks.saturating_add(1);
+            if limit == 0 || model.missed_checks < limit || model.status == ModelStatus::Offline {
+                continue;
+            }
+            
+            warn!(model_id = %id, "'{}' missed {} checks in a row", model.name, model.missed_checks);
+            model.dependency_degraded = false;
+            let old_status = model.set_status(ModelStatus::Offline, now);
+            let mut alert = Alert::new(model, old_status);
+            alert.timestamp = now;
+            transitions.push(Transition {
+                alert,
+                remediate: None,
+                in_warmup: model.in_warmup(&state.config, now),
+            });
+        }
+        if transitions.is_empty() {
+            return;
+        }
+        
+        transitions.extend(dependencies::propagate_dependencies(&mut state.monitored_models, &state.config, now));
+        recompute_metrics(state);
+        let config = state.config.clone();
+        drop(guard);
+        
+        self.finish_transitions(&config, transitions);
+    }
+}
+
+/// When a model was last due, unjittered, and the jitter added to its next check
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ScheduleSlot {
+    base: Instant,
+    jitter: Duration,
+}
+
+impl ScheduleSlot {
+    fn due(&self, interval: Duration) -> Instant {
+        self.base + interval + self.jitter
+    }
+}
+
+fn check_period(minutes: u32) -> Duration {
+    Duration::from_secs(u64::from(minutes) * 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StaticSource;
+    use std::sync::atomic::AtomicUsize;
+
+    #[tokio::test]
+    async fn test_force_check_fetches_immediately() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("fixed".to_string(), "llm".to_string()).await.unwrap();
+        let added = plugin.get_model(id).await.unwrap().last_check;
+        plugin.set_metrics_source(StaticSource::new().with_score("fixed", 0.95)).await;
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counter = checks.clone();
+        plugin.set_health_check(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }).await;
+        
+        assert_eq!(plugin.force_check(id).await.unwrap(), ModelStatus::Healthy);
+        
+        let model = plugin.get_model(id).await.unwrap();
+        assert_eq!(model.status, ModelStatus::Healthy);
+        assert_eq!(model.performance_score, 0.95);
+        assert!(model.last_check > added);
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+        assert!(plugin.schedule.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_force_check_errors() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("unscored".to_string(), "llm".to_string()).await.unwrap();
+        let missing = Uuid::new_v4();
+        assert!(matches!(plugin.force_check(missing).await, Err(Error::ModelNotFound(m)) if m == missing));
+        
+        plugin.set_metrics_source(StaticSource::new()).await;
+        assert!(matches!(plugin.force_check(id).await, Err(Error::MetricsSource { id: m, .. }) if m == id));
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Unknown);
+        
+        plugin.pause_model(id).await.unwrap();
+        assert!(matches!(plugin.force_check(id).await, Err(Error::ModelPaused(_))));
+    }
+
+    #[tokio::test]
+    async fn test_force_check_without_source_marks_model_due() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("pushed".to_string(), "llm".to_string()).await.unwrap();
+        let other = plugin.add_model("other".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.8).await.unwrap();
+        let slot = ScheduleSlot {
+            base: Instant::now(),
+            jitter: Duration::ZERO,
+        };
+        plugin.schedule.lock().unwrap().extend([(id, slot), (other, slot)]);
+        
+        assert_eq!(plugin.force_check(id).await.unwrap(), ModelStatus::Degraded);
+        
+        let schedule = plugin.schedule.lock().unwrap();
+        assert!(!schedule.contains_key(&id));
+        assert!(schedule.contains_key(&other));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_force_check_without_source_wakes_monitoring_loop() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("pushed".to_string(), "llm".to_string()).await.unwrap();
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counter = checks.clone();
+        plugin.set_health_check(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }).await;
+        let handle = plugin.start_monitoring();
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+        
+        // One check from `force_check` itself, then the loop's, long before the interval is up
+        plugin.force_check(id).await.unwrap();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 3);
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_new_model_wakes_monitoring_loop() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.add_model("first".to_string(), "llm".to_string()).await.unwrap();
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counter = checks.clone();
+        plugin.set_health_check(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }).await;
+        let handle = plugin.start_monitoring();
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+        
+        plugin.add_model("second".to_string(), "llm".to_string()).await.unwrap();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 2);
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitoring_loop_ticks_until_stopped() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        plugin.add_model("b".to_string(), "llm".to_string()).await.unwrap();
+        
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counter = checks.clone();
+        plugin.set_health_check(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }).await;
+        
+        let handle = plugin.start_monitoring();
+        
+        // First tick fires immediately, the second after the 5 minute default interval
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 2);
+        tokio::time::sleep(Duration::from_secs(5 * 60)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 4);
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+        
+        tokio::time::sleep(Duration::from_secs(10 * 60)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitoring_loop_adopts_new_interval() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counter = checks.clone();
+        plugin.set_health_check(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }).await;
+        
+        let handle = plugin.start_monitoring();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+        
+        let mut config = plugin.state.read().await.config.clone();
+        config.check_interval_minutes = 1;
+        plugin.update_config(config).await.unwrap();
+        
+        // Under the old 5 minute interval nothing would fire for several minutes
+        tokio::time::sleep(Duration::from_secs(3 * 60 + 30)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 4);
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stale_models_oldest_first() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let fresh = plugin.add_model("fresh".to_string(), "llm".to_string()).await.unwrap();
+        let old = plugin.add_model("old".to_string(), "llm".to_string()).await.unwrap();
+        let older = plugin.add_model("older".to_string(), "llm".to_string()).await.unwrap();
+        let now = Utc::now();
+        {
+            let mut state = plugin.state.write().await;
+            state.model_mut(old).unwrap().last_check = now - chrono::Duration::hours(2);
+            state.model_mut(older).unwrap().last_check = now - chrono::Duration::hours(5);
+        }
+        
+        assert_eq!(plugin.stale_models(Duration::from_secs(3600)).await, vec![older, old]);
+        assert_eq!(plugin.stale_models(Duration::from_secs(3 * 3600)).await, vec![older]);
+        assert!(plugin.stale_models(Duration::from_secs(24 * 3600)).await.is_empty());
+        
+        // The check cycle visits the least recently checked models first
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let visited = order.clone();
+        plugin.set_health_check(move |model| visited.lock().unwrap().push(model.id)).await;
+        plugin.run_checks().await;
+        assert_eq!(*order.lock().unwrap(), vec![older, old, fresh]);
+    }
+
+    #[tokio::test]
+    async fn test_check_cycle_pulls_from_metrics_source() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let good = plugin.add_model("good".to_string(), "llm".to_string()).await.unwrap();
+        let bad = plugin.add_model("bad".to_string(), "llm".to_string()).await.unwrap();
+        let missing = plugin.add_model("missing".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_metrics_source(
+            crate::StaticSource::new()
+                .with_score("good", 0.95)
+                .with_score("bad", 0.2),
+        ).await;
+        
+        plugin.run_checks().await;
+        
+        let state = plugin.state.read().await;
+        assert_eq!(state.monitored_models[&good].status, crate::ModelStatus::Healthy);
+        assert_eq!(state.monitored_models[&bad].status, crate::ModelStatus::Critical);
+        // A failed fetch is skipped without aborting the others
+        assert_eq!(state.monitored_models[&missing].status, crate::ModelStatus::Unknown);
+        assert!(state.monitored_models[&missing].history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_silent_push_model_goes_offline_until_it_reports() {
+        let (addr, mut requests) = crate::test_support::mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            offline_after_missed_checks: 3,
+            ..Default::default()
+        }).await.unwrap();
+        let clock = crate::MockClock::new(chrono::Utc::now());
+        plugin.set_clock(clock.clone());
+        let id = plugin.add_model("pushed".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.95).await.unwrap();
+        let interval = chrono::Duration::minutes(i64::from(plugin.state.read().await.config.check_interval_minutes));
+        
+        for missed in 1..3 {
+            clock.advance(interval + chrono::Duration::seconds(1));
+            plugin.run_checks().await;
+            let model = plugin.get_model(id).await.unwrap();
+            assert_eq!(model.missed_checks, missed);
+            assert_eq!(model.status, ModelStatus::Healthy);
+        }
+        clock.advance(interval + chrono::Duration::seconds(1));
+        plugin.run_checks().await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Offline);
+        
+        let alert: crate::Alert = serde_json::from_str(&requests.recv().await.unwrap().body).unwrap();
+        assert_eq!(alert.old_status, ModelStatus::Healthy);
+        assert_eq!(alert.new_status, ModelStatus::Offline);
+        
+        plugin.record_performance(id, 0.95).await.unwrap();
+        let model = plugin.get_model(id).await.unwrap();
+        assert_eq!(model.status, ModelStatus::Healthy);
+        assert_eq!(model.missed_checks, 0);
+        let alert: crate::Alert = serde_json::from_str(&requests.recv().await.unwrap().body).unwrap();
+        assert_eq!(alert.old_status, ModelStatus::Offline);
+        assert_eq!(alert.new_status, ModelStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_fetch_failures_take_model_offline() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            offline_after_missed_checks: 2,
+            ..Default::default()
+        }).await.unwrap();
+        let id = plugin.add_model("unreachable".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_metrics_source(StaticSource::new()).await;
+        
+        plugin.run_checks().await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Unknown);
+        plugin.run_checks().await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Offline);
+        
+        plugin.set_metrics_source(StaticSource::new().with_score("unreachable", 0.9)).await;
+        plugin.run_checks().await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Healthy);
+    }
+
+    /// Hangs for the model named "hung", scores everything else 0.95
+    struct HangingSource;
+
+    #[async_trait::async_trait]
+    impl crate::MetricsSource for HangingSource {
+        async fn fetch(&self, model: &MonitoredModel) -> anyhow::Result<f64> {
+            if model.name == "hung" {
+                tokio::time::sleep(Duration::from_secs(3600)).await;
+            }
+            Ok(0.95)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_hung_fetch_times_out_and_cycle_continues() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            operation_timeout_seconds: 5,
+            ..Default::default()
+        }).await.unwrap();
+        let hung = plugin.add_model("hung".to_string(), "llm".to_string()).await.unwrap();
+        let fine = plugin.add_model("fine".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_metrics_source(HangingSource).await;
+        
+        let started = Instant::now();
+        plugin.run_checks().await;
+        
+        assert!(started.elapsed() < Duration::from_secs(10));
+        let state = plugin.state.read().await;
+        assert_eq!(state.monitored_models[&hung].status, crate::ModelStatus::Unknown);
+        assert_eq!(state.monitored_models[&fine].status, crate::ModelStatus::Healthy);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_per_model_check_intervals() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let cheap = plugin.add_model("cheap".to_string(), "llm".to_string()).await.unwrap();
+        let expensive = plugin.add_model("expensive".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_check_interval(cheap, Some(1)).await.unwrap();
+        plugin.set_check_interval(expensive, Some(3)).await.unwrap();
+        assert!(matches!(plugin.set_check_interval(cheap, Some(0)).await, Err(Error::InvalidCheckInterval)));
+        
+        let checks = Arc::new(std::sync::Mutex::new(HashMap::<Uuid, usize>::new()));
+        let counter = checks.clone();
+        plugin.set_health_check(move |model| {
+            *counter.lock().unwrap().entry(model.id).or_default() += 1;
+        }).await;
+        
+        let handle = plugin.start_monitoring();
+        
+        // Both are checked at 0; cheap every minute after, expensive at 3 and 6
+        tokio::time::sleep(Duration::from_secs(6 * 60 + 30)).await;
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+        
+        let checks = checks.lock().unwrap();
+        assert_eq!(checks[&cheap], 7);
+        assert_eq!(checks[&expensive], 3);
+    }
+
+    /// Jitter of each model's first reschedule, in model name order
+    async fn jitter_run(seed: u64) -> Vec<Duration> {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            check_jitter_seconds: 60,
+            check_jitter_seed: Some(seed),
+            ..Default::default()
+        }).await.unwrap();
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            ids.push(plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap());
+        }
+        
+        let start = Instant::now();
+        let handle = plugin.start_monitoring();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let next = plugin.next_check_times().await;
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+        
+        ids.iter().map(|id| next[id] - start - check_period(5)).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_seeded_jitter_is_reproducible() {
+        let first = jitter_run(42).await;
+        let second = jitter_run(42).await;
+        
+        assert_eq!(first, second);
+        assert!(first.iter().all(|jitter| *jitter <= Duration::from_secs(60)));
+        assert!(first.iter().any(|jitter| !jitter.is_zero()));
+        assert_ne!(jitter_run(7).await, first);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitoring_resumes_after_panic() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counter = checks.clone();
+        plugin.set_health_check(move |_| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                panic!("buggy health check");
+            }
+        }).await;
+        
+        let handle = plugin.start_monitoring();
+        
+        // The first check panics; the loop is restarted after a second and checks again
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 2);
+        assert_eq!(plugin.monitor_restarts(), 1);
+        assert!(plugin.prometheus_metrics().await.contains("adios_monitor_restarts_total 1\n"));
+        
+        // And carries on at the normal cadence
+        tokio::time::sleep(Duration::from_secs(5 * 60)).await;
+        assert_eq!(checks.load(Ordering::SeqCst), 3);
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_supervisor_gives_up_after_max_restarts() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            monitor_max_restarts: 2,
+            ..Default::default()
+        }).await.unwrap();
+        plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_health_check(|_| panic!("always broken")).await;
+        
+        let handle = plugin.start_monitoring();
+        
+        tokio::time::timeout(Duration::from_secs(60), handle).await.unwrap().unwrap();
+        assert_eq!(plugin.monitor_restarts(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+//! Per-model incident notes
+//!
+//! Operators annotate what happened to a model during an incident. Notes
+//! are append-only, kept oldest first alongside the status history, and
+//! capped at `notes_capacity` with the oldest dropped first.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, StateEvent};
+
+/// A timestamped annotation on a model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Note {
+    pub timestamp: DateTime<Utc>,
+    pub author: String,
+    pub text: String,
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Append a note to a model, dropping its oldest notes beyond
+    /// `notes_capacity`. The text must not be empty.
+    pub async fn add_note(&self, id: Uuid, author: &str, text: &str) -> Result<Note, Error> {
+        if text.trim().is_empty() {
+            return Err(Error::EmptyNote);
+        }
+        
+        let note = Note {
+            timestamp: self.now(),
+            author: author.trim().to_string(),
+            text: text.to_string(),
+        };
+        let mut state = self.state.write().await;
+        let capacity = state.config.notes_capacity;
+        let model = state.model_mut(id)?;
+        model.notes.push(note.clone());
+        let excess = model.notes.len().saturating_sub(capacity);
+        model.notes.drain(..excess);
+        drop(state);
+        
+        self.emit(StateEvent::ModelUpdated(id));
+        Ok(note)
+    }
+    
+    /// A model's notes, oldest first
+    pub async fn model_notes(&self, id: Uuid) -> Result<Vec<Note>, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(model.notes.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModelType, PluginConfig};
+
+    #[tokio::test]
+    async fn test_add_and_read_notes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), ModelType::LLM).await.unwrap();
+        
+        plugin.add_note(id, "alice", "Rolled back to v12 after latency spike").await.unwrap();
+        let note = plugin.add_note(id, "bob", "Root cause: stale feature cache").await.unwrap();
+        assert_eq!(note.author, "bob");
+        
+        let notes = plugin.model_notes(id).await.unwrap();
+        let texts: Vec<&str> = notes.iter().map(|n| n.text.as_str()).collect();
+        assert_eq!(texts, vec!["Rolled back to v12 after latency spike", "Root cause: stale feature cache"]);
+        assert!(notes[0].timestamp <= notes[1].timestamp);
+        
+        // Notes travel with the model in exports
+        let json: serde_json::Value = serde_json::from_str(&plugin.export_json().await.unwrap()).unwrap();
+        assert_eq!(json[0]["notes"][1]["author"], "bob");
+        
+        assert!(matches!(plugin.add_note(id, "alice", "  ").await, Err(Error::EmptyNote)));
+        assert!(matches!(plugin.model_notes(Uuid::new_v4()).await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_notes_capped_oldest_dropped() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            notes_capacity: 3,
+            ..Default::default()
+        }).await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), ModelType::LLM).await.unwrap();
+        
+        for i in 0..5 {
+            plugin.add_note(id, "ops", &format!("note {}", i)).await.unwrap();
+        }
+        
+        let texts: Vec<String> = plugin.model_notes(id).await.unwrap().into_iter().map(|n| n.text).collect();
+        assert_eq!(texts, vec!["note 2", "note 3", "note 4"]);
+    }
+}
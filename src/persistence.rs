@@ -0,0 +1,528 @@
+//! State persistence
+//!
+//! Saves and restores `PluginState` so monitored models survive restarts.
+//! State is stored as JSON, or as MessagePack with the `binary-state`
+//! feature, which is much smaller for fleets with long histories.
+//!
+//! Every file carries a top-level `schema_version`. Older files are
+//! upgraded to the current shape on load; newer ones are rejected rather
+//! than silently losing the fields this build doesn't know about.
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{widen_score, Error, ModelPerformanceMonitoringPlugin, MonitoredModel, PluginState};
+
+/// Version of the persisted state shape written by this build
+pub const STATE_SCHEMA_VERSION: u32 = 3;
+
+/// Upgrades from older versions, applied in order after decoding:
+/// `MIGRATIONS[n - 1]` takes version `n` to `n + 1`. Fields are added with
+/// serde defaults; a step fixes up whatever a default cannot express.
+const MIGRATIONS: [fn(&mut PluginState); STATE_SCHEMA_VERSION as usize - 1] = [migrate_v1, migrate_v2];
+
+/// `PluginState` as written to disk, stamped with its schema version
+#[derive(Serialize)]
+struct VersionedState<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    state: &'a PluginState,
+}
+
+/// Just the version of a state file, absent before versioning
+#[derive(Deserialize)]
+struct VersionProbe {
+    schema_version: Option<u64>,
+}
+
+/// Encoding of a state file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateFormat {
+    /// Pretty-printed JSON
+    Json,
+    /// MessagePack with named fields
+    #[cfg(feature = "binary-state")]
+    MessagePack,
+}
+
+impl StateFormat {
+    /// Format implied by the file extension: `.msgpack` or `.mpk` for
+    /// MessagePack (with the `binary-state` feature), JSON otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "binary-state")]
+            Some("msgpack" | "mpk") => StateFormat::MessagePack,
+            _ => StateFormat::Json,
+        }
+    }
+    
+    fn encode(self, state: &PluginState) -> Result<Vec<u8>, Error> {
+        let versioned = VersionedState {
+            schema_version: STATE_SCHEMA_VERSION,
+            state,
+        };
+        match self {
+            StateFormat::Json => Ok(serde_json::to_vec_pretty(&versioned)?),
+            #[cfg(feature = "binary-state")]
+            StateFormat::MessagePack => Ok(rmp_serde::to_vec_named(&versioned)?),
+        }
+    }
+    
+    /// Decode a state of any known schema version, upgraded to the current one
+    fn decode(self, path: &Path, bytes: &[u8]) -> Result<PluginState, Error> {
+        let version = self.decode_as::<VersionProbe>(path, bytes)?.schema_version.unwrap_or(1);
+        if version > u64::from(STATE_SCHEMA_VERSION) {
+            return Err(Error::UnsupportedStateVersion {
+                path: path.to_path_buf(),
+                version,
+                supported: STATE_SCHEMA_VERSION,
+            });
+        }
+        
+        let mut state = self.decode_as::<PluginState>(path, bytes)?;
+        for (from, step) in MIGRATIONS.iter().enumerate().skip(version.saturating_sub(1) as usize) {
+            info!("Migrating state file {} from schema version {}", path.display(), from + 1);
+            step(&mut state);
+        }
+        Ok(state)
+    }
+    
+    fn decode_as<T: DeserializeOwned>(self, path: &Path, bytes: &[u8]) -> Result<T, Error> {
+        match self {
+            StateFormat::Json => serde_json::from_slice(bytes).map_err(|source| Error::CorruptState {
+                path: path.to_path_buf(),
+                source,
+            }),
+            #[cfg(feature = "binary-state")]
+            StateFormat::MessagePack => rmp_serde::from_slice(bytes).map_err(|source| Error::CorruptBinaryState {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Write the current state to `path` in the format its extension implies
+    pub async fn save_state(&self, path: &Path) -> Result<(), Error> {
+        self.save_state_as(path, StateFormat::from_path(path)).await
+    }
+    
+    /// Write the current state to `path` in `format`.
+    ///
+    /// The state is written to a sibling temp file first and renamed into
+    /// place, so a crash mid-write never leaves a truncated state file.
+    pub async fn save_state_as(&self, path: &Path, format: StateFormat) -> Result<(), Error> {
+        let bytes = {
+            let state = self.state.read().await;
+            format.encode(&state)?
+        };
+        
+        let tmp_path = temp_path(path);
+        tokio::fs::write(&tmp_path, &bytes).await.map_err(|source| Error::StateFile {
+            path: tmp_path.clone(),
+            source,
+        })?;
+        tokio::fs::rename(&tmp_path, path).await.map_err(|source| Error::StateFile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        
+        Ok(())
+    }
+    
+    /// Read a previously saved state from `path` in the format its extension implies
+    pub async fn load_state(&self, path: &Path) -> Result<PluginState, Error> {
+        self.load_state_as(path, StateFormat::from_path(path)).await
+    }
+    
+    /// Read a previously saved state from `path` in `format`.
+    ///
+    /// Timestamps written under clock skew are checked against the plugin
+    /// clock: a `last_check` or `status_since` in the future is clamped to
+    /// now, and a `created_at` after `last_check` is clamped to
+    /// `last_check`, each with a warning. With `strict_state_validation`
+    /// set, loading fails instead.
+    pub async fn load_state_as(&self, path: &Path, format: StateFormat) -> Result<PluginState, Error> {
+        read_state(path, format, self.now()).await
+    }
+}
+
+/// Decode, validate and repair the state at `path` as of `now`. The
+/// constructors call this before a plugin (and its clock) exists.
+pub(crate) async fn read_state(path: &Path, format: StateFormat, now: DateTime<Utc>) -> Result<PluginState, Error> {
+    let bytes = tokio::fs::read(path).await.map_err(|source| Error::StateFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    
+    let mut state = format.decode(path, &bytes)?;
+    state.config.validate()?;
+    
+    let strict = state.config.strict_state_validation;
+    for model in state.monitored_models.values_mut() {
+        fix_timestamps(Arc::make_mut(model), now, strict).map_err(|reason| Error::InvalidTimestamps {
+            path: path.to_path_buf(),
+            reason,
+        })?;
+    }
+    
+    // The capacity may have been lowered since the file was written
+    let capacity = state.config.history_capacity;
+    for model in state.monitored_models.values_mut() {
+        let excess = model.history.len().saturating_sub(capacity);
+        if excess > 0 {
+            Arc::make_mut(model).history.drain(..excess);
+        }
+    }
+    
+    Ok(state)
+}
+
+/// Version 1 files predate `schema_version`. Every field added since carries
+/// a serde default, so they load as they are.
+fn migrate_v1(_state: &mut PluginState) {}
+
+/// Version 2 stored scores as `f32`. Binary files hold them as 32-bit
+/// floats, which widen to values like 0.699999988, so every score is
+/// snapped back to the decimal it was written as.
+fn migrate_v2(state: &mut PluginState) {
+    let archived = state.archived_models.values_mut().map(|archived| &mut Arc::make_mut(archived).model);
+    for model in state.monitored_models.values_mut().map(Arc::make_mut).chain(archived) {
+        model.performance_score = widen_score(model.performance_score as f32);
+        for (_, score) in &mut model.history {
+            *score = widen_score(*score as f32);
+        }
+    }
+}
+
+/// Enforce `created_at <= last_check <= now` and `status_since <= now`,
+/// clamping with a warning or, when `strict`, describing the first violation
+fn fix_timestamps(model: &mut MonitoredModel, now: DateTime<Utc>, strict: bool) -> Result<(), String> {
+    let clamp = |field: &str, value: &mut DateTime<Utc>, limit: DateTime<Utc>, limit_name: &str| {
+        if *value <= limit {
+            return Ok(());
+        }
+        let reason = format!("model '{}' has {} {} after {} {}", model.name, field, value, limit_name, limit);
+        if strict {
+            return Err(reason);
+        }
+        warn!(model_id = %model.id, "{}; clamping", reason);
+        *value = limit;
+        Ok(())
+    };
+    
+    clamp("last_check", &mut model.last_check, now, "now")?;
+    clamp("status_since", &mut model.status_since, now, "now")?;
+    clamp("created_at", &mut model.created_at, model.last_check, "last_check")?;
+    Ok(())
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn scratch_path() -> PathBuf {
+        std::env::temp_dir().join(format!("adios-mpm-state-{}.json", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_round_trip() {
+        let path = scratch_path();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("persisted".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.7).await.unwrap();
+        
+        plugin.save_state(&path).await.unwrap();
+        assert!(!temp_path(&path).exists());
+        
+        let restored = ModelPerformanceMonitoringPlugin::with_state_file(&path).await.unwrap();
+        let state = restored.state.read().await;
+        let model = &state.monitored_models[&id];
+        assert_eq!(model.name, "persisted");
+        assert_eq!(model.performance_score, 0.7);
+        assert_eq!(state.system_metrics.total_models, 1);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_external_ids_survive_reload() {
+        let path = scratch_path();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let key = Some("cp-7".to_string());
+        let id = plugin.add_model_with_external_id("keyed".to_string(), "llm".to_string(), key.clone()).await.unwrap();
+        plugin.save_state(&path).await.unwrap();
+        
+        let restored = ModelPerformanceMonitoringPlugin::with_state_file(&path).await.unwrap();
+        let retry = restored.add_model_with_external_id("keyed".to_string(), "llm".to_string(), key).await.unwrap();
+        assert_eq!(retry, id);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_missing_state_file_starts_empty() {
+        let plugin = ModelPerformanceMonitoringPlugin::with_state_file(&scratch_path()).await.unwrap();
+        
+        assert!(plugin.state.read().await.monitored_models.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_load_corrupt_state_file() {
+        let path = scratch_path();
+        std::fs::write(&path, b"{ not json").unwrap();
+        
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let err = plugin.load_state(&path).await.unwrap_err();
+        assert!(err.to_string().contains("corrupt"));
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_trims_history_to_capacity() {
+        let path = scratch_path();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("long".to_string(), "llm".to_string()).await.unwrap();
+        for _ in 0..10 {
+            plugin.record_performance(id, 0.9).await.unwrap();
+        }
+        let mut state = plugin.state.write().await;
+        state.config.history_capacity = 4;
+        state.config.drift_recent_window = 2;
+        state.config.drift_baseline_window = 2;
+        drop(state);
+        plugin.save_state(&path).await.unwrap();
+        
+        let state = plugin.load_state(&path).await.unwrap();
+        assert_eq!(state.monitored_models[&id].history.len(), 4);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    async fn save_with(path: &Path, strict: bool, skew: impl FnOnce(&mut MonitoredModel)) -> (ModelPerformanceMonitoringPlugin, uuid::Uuid) {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("skewed".to_string(), "llm".to_string()).await.unwrap();
+        let mut state = plugin.state.write().await;
+        state.config.strict_state_validation = strict;
+        skew(state.model_mut(id).unwrap());
+        drop(state);
+        plugin.save_state(path).await.unwrap();
+        (plugin, id)
+    }
+
+    #[tokio::test]
+    async fn test_load_clamps_future_last_check() {
+        let path = scratch_path();
+        let future = Utc::now() + chrono::Duration::hours(3);
+        let (plugin, id) = save_with(&path, false, |m| m.last_check = future).await;
+        
+        let state = plugin.load_state(&path).await.unwrap();
+        let model = &state.monitored_models[&id];
+        assert!(model.last_check < future);
+        assert!(model.last_check <= Utc::now());
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_clamps_created_after_last_check() {
+        let path = scratch_path();
+        let (plugin, id) = save_with(&path, false, |m| {
+            m.last_check = Utc::now() - chrono::Duration::hours(2);
+            m.created_at = Utc::now() - chrono::Duration::hours(1);
+        }).await;
+        
+        let state = plugin.load_state(&path).await.unwrap();
+        let model = &state.monitored_models[&id];
+        assert_eq!(model.created_at, model.last_check);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_clamps_against_plugin_clock() {
+        let path = scratch_path();
+        let (plugin, id) = save_with(&path, false, |_| {}).await;
+        let past = Utc::now() - chrono::Duration::days(1);
+        plugin.set_clock(crate::MockClock::new(past));
+        
+        let state = plugin.load_state(&path).await.unwrap();
+        let model = &state.monitored_models[&id];
+        assert_eq!((model.last_check, model.status_since), (past, past));
+        assert_eq!(model.created_at, past);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_strict_load_rejects_skewed_timestamps() {
+        let path = scratch_path();
+        let (plugin, _) = save_with(&path, true, |m| m.created_at = m.last_check + chrono::Duration::minutes(5)).await;
+        
+        let err = plugin.load_state(&path).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidTimestamps { .. }));
+        assert!(err.to_string().contains("created_at"));
+        
+        let future = Utc::now() + chrono::Duration::days(1);
+        let (plugin, _) = save_with(&path, true, |m| m.last_check = future).await;
+        let err = plugin.load_state(&path).await.unwrap_err();
+        assert!(err.to_string().contains("last_check"));
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "binary-state")]
+    #[tokio::test]
+    async fn test_binary_state_round_trips_like_json() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        for i in 0..200 {
+            let id = plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap();
+            plugin.add_tag(id, "production").await.unwrap();
+            plugin.set_metadata(id, "owner", "ml-platform").await.unwrap();
+            for j in 0..50 {
+                plugin.record_performance(id, 0.5 + (i * j % 50) as f64 / 100.0).await.unwrap();
+            }
+        }
+        let json_path = scratch_path();
+        let binary_path = json_path.with_extension("msgpack");
+        assert_eq!(StateFormat::from_path(&binary_path), StateFormat::MessagePack);
+        
+        plugin.save_state(&json_path).await.unwrap();
+        plugin.save_state(&binary_path).await.unwrap();
+        
+        let original = serde_json::to_value(plugin.snapshot().await).unwrap();
+        let from_json = plugin.load_state(&json_path).await.unwrap();
+        let from_binary = plugin.load_state(&binary_path).await.unwrap();
+        assert_eq!(serde_json::to_value(&from_binary).unwrap(), original);
+        assert_eq!(serde_json::to_value(&from_json).unwrap(), original);
+        
+        let json_size = std::fs::metadata(&json_path).unwrap().len();
+        let binary_size = std::fs::metadata(&binary_path).unwrap().len();
+        // Scores are 9-byte doubles in MessagePack, most of the history's size
+        assert!(binary_size < json_size * 2 / 3, "binary {} bytes vs json {} bytes", binary_size, json_size);
+        
+        // Either format can be read explicitly regardless of extension
+        let err = plugin.load_state_as(&binary_path, StateFormat::Json).await.unwrap_err();
+        assert!(matches!(err, Error::CorruptState { .. }));
+        
+        std::fs::remove_file(&json_path).unwrap();
+        std::fs::remove_file(&binary_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_saved_state_is_versioned() {
+        let path = scratch_path();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.save_state(&path).await.unwrap();
+        
+        let json: Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(json["schema_version"], STATE_SCHEMA_VERSION);
+        plugin.load_state(&path).await.unwrap();
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_migrates_v1_state() {
+        let path = scratch_path();
+        // Written before schema versioning, with only the original fields
+        let v1 = serde_json::json!({
+            "monitored_models": {
+                "67e55044-10b1-426f-9247-bb680e5fe0c8": {
+                    "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                    "name": "legacy",
+                    "model_type": "LLM",
+                    "status": "Healthy",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "last_check": "2024-01-02T00:00:00Z",
+                    "performance_score": 0.9
+                }
+            },
+            "system_metrics": {
+                "total_models": 1,
+                "healthy_models": 1,
+                "degraded_models": 0,
+                "average_performance": 0.9
+            },
+            "config": {
+                "check_interval_minutes": 10,
+                "performance_threshold": 0.8,
+                "auto_remediation": false,
+                "alert_enabled": true
+            }
+        });
+        std::fs::write(&path, v1.to_string()).unwrap();
+        
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let state = plugin.load_state(&path).await.unwrap();
+        let model = state.monitored_models.values().next().unwrap();
+        assert_eq!((model.name.as_str(), model.model_type.clone()), ("legacy", crate::ModelType::LLM));
+        assert_eq!(model.importance, 1.0);
+        assert!(model.history.is_empty() && model.notes.is_empty() && model.depends_on.is_empty());
+        assert!(!model.paused && model.deleted_at.is_none());
+        assert_eq!(state.system_metrics.unknown_models, 0);
+        assert_eq!((state.config.check_interval_minutes, state.config.performance_threshold), (10, 0.8));
+        assert_eq!(state.config.history_capacity, crate::PluginConfig::default().history_capacity);
+        assert!(state.archived_models.is_empty());
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_migrates_v2_f32_scores() {
+        let path = scratch_path();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("legacy".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.7).await.unwrap();
+        plugin.save_state(&path).await.unwrap();
+        
+        // As read back from a version 2 binary file, where scores were 32-bit floats
+        let widened = f64::from(0.7f32);
+        let mut json: Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        json["schema_version"] = Value::from(2);
+        let model = &mut json["monitored_models"][id.to_string()];
+        model["performance_score"] = Value::from(widened);
+        model["history"][0][1] = Value::from(widened);
+        std::fs::write(&path, json.to_string()).unwrap();
+        
+        let state = plugin.load_state(&path).await.unwrap();
+        let model = &state.monitored_models[&id];
+        assert_eq!(model.performance_score, 0.7);
+        assert_eq!(model.history[0].1, 0.7);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_rejects_newer_schema_version() {
+        let path = scratch_path();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.save_state(&path).await.unwrap();
+        let mut json: Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        json["schema_version"] = Value::from(STATE_SCHEMA_VERSION + 1);
+        std::fs::write(&path, json.to_string()).unwrap();
+        
+        let err = plugin.load_state(&path).await.unwrap_err();
+        assert!(matches!(err, Error::UnsupportedStateVersion { version: 4, supported: 3, .. }));
+        
+        json["schema_version"] = Value::from("two");
+        std::fs::write(&path, json.to_string()).unwrap();
+        let err = plugin.load_state(&path).await.unwrap_err();
+        assert!(matches!(err, Error::CorruptState { .. }));
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+//! Prometheus Pushgateway client
+//!
+//! For deployments that cannot be scraped, the monitoring loop pushes the
+//! `prometheus_metrics` output to `pushgateway_url` under the configured
+//! job every `pushgateway_interval_seconds`, independently of the check
+//! interval. Pushes run in the background so a slow or failing gateway
+//! never delays model checks.
+
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{debug, error, warn};
+
+use crate::ModelPerformanceMonitoringPlugin;
+
+/// Number of times a push is attempted before giving up until the next interval
+const MAX_PUSH_ATTEMPTS: u32 = 3;
+
+/// Delay between push attempts, multiplied by the attempt number
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+impl ModelPerformanceMonitoringPlugin {
+    /// When the next push is due, or `None` without a `pushgateway_url`
+    pub(crate) async fn next_push(&self) -> Option<Instant> {
+        let config = self.current_config();
+        config.pushgateway_url.as_ref()?;
+        let interval = Duration::from_secs(config.pushgateway_interval_seconds);
+        let last_push = *self.last_push.lock().unwrap();
+        Some(last_push.map_or_else(Instant::now, |last| last + interval))
+    }
+    
+    /// Push the current metrics in the background if a push is due
+    pub(crate) async fn push_metrics_if_due(&self) {
+        if self.next_push().await.is_none_or(|due| due > Instant::now()) {
+            return;
+        }
+        *self.last_push.lock().unwrap() = Some(Instant::now());
+        
+        let (url, job) = {
+            let config = self.current_config();
+            let Some(base) = &config.pushgateway_url else { return };
+            (format!("{}/metrics/job/{}", base.trim_end_matches('/'), config.pushgateway_job), config.pushgateway_job.clone())
+        };
+        let body = self.prometheus_metrics().await;
+        let client = self.http_client.clone();
+        tokio::spawn(async move { push(&client, &url, &job, body).await });
+    }
+}
+
+/// PUT `body` to the job's group on the pushgateway, replacing its previous
+/// metrics, retrying a bounded number of times
+async fn push(client: &reqwest::Client, url: &str, job: &str, body: String) -> bool {
+    for attempt in 1..=MAX_PUSH_ATTEMPTS {
+        let result = client
+            .put(url)
+            .header(reqwest::header::CONTENT_TYPE, "text/plain; version=0.0.4")
+            .body(body.clone())
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        
+        match result {
+            Ok(_) => {
+                debug!(job, "Pushed metrics to pushgateway");
+                return true;
+            }
+            Err(e) if attempt < MAX_PUSH_ATTEMPTS => {
+                warn!(job, attempt, "Metrics push failed, retrying: {}", e);
+                tokio::time::sleep(RETRY_DELAY * attempt).await;
+            }
+            Err(e) => {
+                error!(job, "Giving up on metrics push after {} attempts: {}", attempt, e);
+            }
+        }
+    }
+    
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock_http_server;
+    use crate::PluginConfig;
+
+    async fn pushing_plugin(addr: std::net::SocketAddr, interval_seconds: u64) -> ModelPerformanceMonitoringPlugin {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            pushgateway_url: Some(format!("http://{}/", addr)),
+            pushgateway_job: "adios-test".to_string(),
+            pushgateway_interval_seconds: interval_seconds,
+            ..Default::default()
+        }).await.unwrap();
+        plugin.add_model("pushed".to_string(), "llm".to_string()).await.unwrap();
+        plugin
+    }
+
+    #[tokio::test]
+    async fn test_metrics_are_pushed_on_their_own_interval() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = pushing_plugin(addr, 1).await;
+        let handle = plugin.start_monitoring();
+        
+        let first = tokio::time::timeout(Duration::from_secs(5), requests.recv()).await.unwrap().unwrap();
+        assert_eq!(first.request_line, "PUT /metrics/job/adios-test HTTP/1.1");
+        assert!(first.body.contains("adios_models_total 1\n"));
+        assert!(first.header("content-type").unwrap().starts_with("text/plain"));
+        
+        // Well within the 5 minute check interval
+        let second = tokio::time::timeout(Duration::from_secs(5), requests.recv()).await.unwrap().unwrap();
+        assert_eq!(second.request_line, first.request_line);
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failed_push_is_retried() {
+        let (addr, mut requests) = mock_http_server(500).await;
+        let plugin = pushing_plugin(addr, 3600).await;
+        let handle = plugin.start_monitoring();
+        
+        for _ in 0..MAX_PUSH_ATTEMPTS {
+            tokio::time::timeout(Duration::from_secs(5), requests.recv()).await.unwrap().unwrap();
+        }
+        assert!(tokio::time::timeout(Duration::from_secs(2), requests.recv()).await.is_err());
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_no_push_without_url() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        assert!(plugin.next_push().await.is_none());
+    }
+}
@@ -0,0 +1,191 @@
+//! Config hot-reload (enabled with the `hot-reload` feature)
+//!
+//! Watches the config file and re-applies it through `update_config`
+//! whenever it changes on disk, so edits take effect without a restart.
+//! Bursts of writes (editors often write a file in several steps) are
+//! debounced into a single reload, and a file that fails to parse or
+//! validate is logged and ignored, leaving the running config in place.
+
+use notify::{RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, PluginConfig};
+
+/// Quiet period after the last change before the file is re-read
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(250);
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Reload the config from the TOML file at `path` whenever it changes,
+    /// until the plugin shuts down.
+    ///
+    /// The file's directory is watched rather than the file itself, so
+    /// editors that save by replacing the file are followed too. Reloaded
+    /// configs get the same `ADIOS_*` environment overrides as at startup.
+    pub fn watch_config(&self, path: impl Into<PathBuf>) -> Result<JoinHandle<()>, Error> {
+        let path = path.into();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let file_name = path.file_name().map(|name| name.to_os_string());
+        
+        let (tx, mut changes) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+            Ok(event) if event.kind.is_access() => {}
+            Ok(event) => {
+                if event.paths.iter().any(|p| p.file_name() == file_name.as_deref()) {
+                    let _ = tx.send(());
+                }
+            }
+            Err(e) => warn!("Config watcher error: {}", e),
+        })
+        .map_err(std::io::Error::other)?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive).map_err(std::io::Error::other)?;
+        info!("Watching {} for config changes", path.display());
+        
+        let plugin = self.clone();
+        Ok(tokio::spawn(async move {
+            // Dropping the watcher stops it, so it lives as long as the task
+            let _watcher = watcher;
+            loop {
+                tokio::select! {
+                    change = changes.recv() => {
+                        if change.is_none() {
+                            break;
+                        }
+                    }
+                    _ = plugin.stopping() => break,
+                }
+                while let Ok(Some(())) = tokio::time::timeout(RELOAD_DEBOUNCE, changes.recv()).await {}
+                
+                plugin.reload_config(&path).await;
+            }
+        }))
+    }
+    
+    /// Re-read, validate and apply the config file at `path`, logging what changed
+    async fn reload_config(&self, path: &Path) {
+        let reloaded = PluginConfig::from_toml_file(path).and_then(|mut config| {
+            config.apply_env_overrides(|name| std::env::var(name).ok())?;
+            Ok(config)
+        });
+        let config = match reloaded {
+            Ok(config) => config,
+            Err(e) => {
+                error!("Ignoring changed config file, keeping the running config: {}", e);
+                return;
+            }
+        };
+        
+        let changes = self.config_diff(&config).await;
+        if changes.is_empty() {
+            debug!("Config file {} changed but its settings did not", path.display());
+            return;
+        }
+        if let Err(e) = self.update_config(config).await {
+            error!("Ignoring changed config file, keeping the running config: {}", e);
+            return;
+        }
+        for change in changes {
+            info!("Config reloaded: {} changed from {} to {}", change.field, change.old, change.new);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Poll until the running check interval is `minutes`
+    async fn wait_for_interval(plugin: &ModelPerformanceMonitoringPlugin, minutes: u32) -> bool {
+        for _ in 0..200 {
+            if plugin.state.read().await.config.check_interval_minutes == minutes {
+                return true;
+            }
+            tokio::time::sleep(Duration::from_millis(25)).await;
+        }
+        false
+    }
+
+    #[tokio::test]
+    async fn test_changed_config_file_is_applied() {
+        let dir = std::env::temp_dir().join(format!("adios-mpm-reload-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "check_interval_minutes = 5\n").unwrap();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let watcher = plugin.watch_config(&path).unwrap();
+        
+        std::fs::write(&path, "check_interval_minutes = 9\nperformance_threshold = 0.7\n").unwrap();
+        assert!(wait_for_interval(&plugin, 9).await);
+        assert_eq!(plugin.state.read().await.config.performance_threshold, 0.7);
+        
+        // Neither an invalid value nor broken TOML replaces the running config
+        std::fs::write(&path, "check_interval_minutes = 0\n").unwrap();
+        std::fs::write(&path, "check_interval_minutes = [\n").unwrap();
+        tokio::time::sleep(RELOAD_DEBOUNCE * 4).await;
+        assert_eq!(plugin.state.read().await.config.check_interval_minutes, 9);
+        
+        std::fs::write(&path, "check_interval_minutes = 3\n").unwrap();
+        assert!(wait_for_interval(&plugin, 3).await);
+        
+        watcher.abort();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_burst_of_writes_reloads_once() {
+        let dir = std::env::temp_dir().join(format!("adios-mpm-reload-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "check_interval_minutes = 5\n").unwrap();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut events = plugin.subscribe();
+        let watcher = plugin.watch_config(&path).unwrap();
+        
+        for minutes in 10..20 {
+            std::fs::write(&path, format!("check_interval_minutes = {}\n", minutes)).unwrap();
+        }
+        assert!(wait_for_interval(&plugin, 19).await);
+        tokio::time::sleep(RELOAD_DEBOUNCE * 2).await;
+        
+        let mut updates = 0;
+        while let Ok(event) = events.try_recv() {
+            updates += usize::from(event == crate::StateEvent::ConfigUpdated);
+        }
+        assert_eq!(updates, 1);
+        
+        watcher.abort();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stop_monitoring_leaves_watcher_running() {
+        let dir = std::env::temp_dir().join(format!("adios-mpm-reload-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "check_interval_minutes = 5\n").unwrap();
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let watcher = plugin.watch_config(&path).unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let monitoring = plugin.start_monitoring();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        
+        plugin.stop_monitoring();
+        tokio::time::timeout(Duration::from_secs(5), monitoring).await.expect("monitoring loop kept running").unwrap();
+        std::fs::write(&path, "check_interval_minutes = 8\n").unwrap();
+        assert!(wait_for_interval(&plugin, 8).await);
+        
+        // Plugin shutdown stops it
+        let running = plugin.clone();
+        let run = tokio::spawn(async move { running.run().await });
+        plugin.request_shutdown();
+        run.await.unwrap().unwrap();
+        tokio::time::timeout(Duration::from_secs(5), watcher).await.unwrap().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
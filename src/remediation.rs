@@ -0,0 +1,340 @@
+//! Auto-remediation
+//!
+//! When a model goes `Critical` and `auto_remediation` is enabled (and the
+//! tier includes it), the registered `RemediationHandler` is run in the
+//! background and its outcome recorded on the model.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{Error, Feature, ModelPerformanceMonitoringPlugin, MonitoredModel, PluginConfig, StateEvent};
+
+/// Corrective action taken against a critical model
+#[async_trait]
+pub trait RemediationHandler: Send + Sync {
+    async fn remediate(&self, model: &MonitoredModel) -> Result<RemediationOutcome>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RemediationOutcome {
+    /// The handler believes the problem is fixed
+    Resolved,
+    /// The handler ran but had nothing to do
+    NoAction,
+    /// The handler could not fix the model
+    Failed { reason: String },
+    /// The handler was not run, e.g. because `dry_run` is enabled
+    Skipped { reason: String },
+    /// The handler did not finish within `operation_timeout_seconds`
+    TimedOut,
+}
+
+/// Outcome of a remediation attempt together with when it finished
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RemediationRecord {
+    pub outcome: RemediationOutcome,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Default handler which takes no action
+pub struct NoopRemediation;
+
+#[async_trait]
+impl RemediationHandler for NoopRemediation {
+    async fn remediate(&self, _model: &MonitoredModel) -> Result<RemediationOutcome> {
+        Ok(RemediationOutcome::NoAction)
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Replace the handler used for auto-remediation.
+    ///
+    /// Fails with `FeatureUnavailable` on tiers without auto-remediation.
+    pub async fn set_remediation_handler<H>(&self, handler: H) -> Result<(), Error>
+    where
+        H: RemediationHandler + 'static,
+    {
+        let tier = self.current_config().tier;
+        if !tier.allows(Feature::AutoRemediation) {
+            return Err(Error::FeatureUnavailable {
+                tier,
+                feature: Feature::AutoRemediation,
+            });
+        }
+        
+        *self.remediation_handler.write().await = Arc::new(handler);
+        Ok(())
+    }
+    
+    /// Run the remediation handler for `model` in the background.
+    ///
+    /// Does nothing if a remediation for the same model is still running. In
+    /// `dry_run` mode the handler is not called and the attempt is recorded
+    /// as `Skipped`; a handler running past `operation_timeout_seconds` is
+    /// abandoned and recorded as `TimedOut`, and one that panics is recorded
+    /// as `Failed`.
+    pub(crate) fn trigger_remediation(&self, config: &PluginConfig, model: MonitoredModel) {
+        if !self.remediating.lock().unwrap().insert(model.id) {
+            info!(model_id = %model.id, "Remediation already in progress, not re-triggering");
+            return;
+        }
+        
+        let plugin = self.clone();
+        let dry_run = config.dry_run;
+        let timeout = config.operation_timeout();
+        tokio::spawn(async move {
+            let outcome = if dry_run {
+                info!(model_id = %model.id, "Dry run: would start remediation for '{}'", model.name);
+                RemediationOutcome::Skipped {
+                    reason: "dry run".to_string(),
+                }
+            } else {
+                let handler = plugin.remediation_handler.read().await.clone();
+                info!(model_id = %model.id, "Starting remediation for '{}'", model.name);
+                
+                // Run in its own task so a panicking handler can't skip the bookkeeping below
+                let target = model.clone();
+                let mut run = tokio::spawn(async move { handler.remediate(&target).await });
+                match tokio::time::timeout(timeout, &mut run).await {
+                    Ok(Ok(Ok(outcome))) => outcome,
+                    Ok(Ok(Err(e))) => {
+                        warn!(model_id = %model.id, "Remediation failed: {}", e);
+                        RemediationOutcome::Failed { reason: e.to_string() }
+                    }
+                    Ok(Err(e)) => {
+                        warn!(model_id = %model.id, "Remediation handler panicked: {}", e);
+                        RemediationOutcome::Failed { reason: format!("handler panicked: {}", e) }
+                    }
+                    Err(_) => {
+                        run.abort();
+                        warn!(model_id = %model.id, "Remediation timed out after {:?}", timeout);
+                        RemediationOutcome::TimedOut
+                    }
+                }
+            };
+            
+            let mut state = plugin.state.write().await;
+            if let Some(current) = state.monitored_models.get_mut(&model.id) {
+                Arc::make_mut(current).last_remediation = Some(RemediationRecord {
+                    outcome,
+                    completed_at: plugin.now(),
+                });
+            }
+            drop(state);
+            plugin.emit(StateEvent::ModelUpdated(model.id));
+            
+            plugin.remediating.lock().unwrap().remove(&model.id);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tokio::sync::Notify;
+    use uuid::Uuid;
+
+    /// Counts invocations and blocks until released
+    struct GatedHandler {
+        calls: Arc<AtomicUsize>,
+        release: Arc<Notify>,
+    }
+
+    #[async_trait]
+    impl RemediationHandler for GatedHandler {
+        async fn remediate(&self, _model: &MonitoredModel) -> Result<RemediationOutcome> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.release.notified().await;
+            Ok(RemediationOutcome::Resolved)
+        }
+    }
+
+    async fn wait_for_remediation(plugin: &ModelPerformanceMonitoringPlugin, id: Uuid) -> RemediationRecord {
+        for _ in 0..100 {
+            if let Some(record) = plugin.state.read().await.monitored_models[&id].last_remediation.clone() {
+                return record;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("remediation outcome was never recorded");
+    }
+
+    #[tokio::test]
+    async fn test_critical_model_is_remediated_once() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(Notify::new());
+        plugin.set_remediation_handler(GatedHandler {
+            calls: calls.clone(),
+            release: release.clone(),
+        }).await.unwrap();
+        let id = plugin.add_model("broken".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        
+        // Going critical again while the first remediation runs must not re-trigger
+        plugin.record_performance(id, 0.9).await.unwrap();
+        plugin.record_performance(id, 0.1).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        
+        release.notify_one();
+        let record = wait_for_remediation(&plugin, id).await;
+        assert_eq!(record.outcome, RemediationOutcome::Resolved);
+    }
+
+    struct PanickingHandler {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl RemediationHandler for PanickingHandler {
+        async fn remediate(&self, _model: &MonitoredModel) -> Result<RemediationOutcome> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            panic!("handler bug");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_panicking_handler_is_recorded_and_retried() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        plugin.set_remediation_handler(PanickingHandler { calls: calls.clone() }).await.unwrap();
+        let id = plugin.add_model("broken".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        let record = wait_for_remediation(&plugin, id).await;
+        assert!(matches!(record.outcome, RemediationOutcome::Failed { reason } if reason.contains("panicked")));
+        
+        // The model isn't left marked as mid-remediation
+        for _ in 0..100 {
+            if plugin.remediating.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(plugin.remediating.lock().unwrap().is_empty());
+        plugin.record_performance(id, 0.9).await.unwrap();
+        plugin.record_performance(id, 0.1).await.unwrap();
+        for _ in 0..100 {
+            if calls.load(Ordering::SeqCst) == 2 {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("remediation was never retried");
+    }
+
+    #[tokio::test]
+    async fn test_remediation_disabled() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(crate::PluginConfig {
+            auto_remediation: false,
+            ..Default::default()
+        }).await.unwrap();
+        let id = plugin.add_model("broken".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        
+        assert!(plugin.state.read().await.monitored_models[&id].last_remediation.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_default_handler_records_no_action() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("broken".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        
+        let record = wait_for_remediation(&plugin, id).await;
+        assert_eq!(record.outcome, RemediationOutcome::NoAction);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_never_calls_handler() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            dry_run: true,
+            ..Default::default()
+        }).await.unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(Notify::new());
+        plugin.set_remediation_handler(GatedHandler {
+            calls: calls.clone(),
+            release,
+        }).await.unwrap();
+        let id = plugin.add_model("broken".to_string(), "llm".to_string()).await.unwrap();
+        let mut events = plugin.subscribe();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        
+        let record = wait_for_remediation(&plugin, id).await;
+        assert_eq!(record.outcome, RemediationOutcome::Skipped { reason: "dry run".to_string() });
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        
+        let mut updated = false;
+        while let Ok(event) = events.try_recv() {
+            updated |= event == StateEvent::ModelUpdated(id);
+        }
+        assert!(updated);
+    }
+
+    #[tokio::test]
+    async fn test_starter_tier_refuses_auto_remediation() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            tier: crate::PricingTierName::Starter,
+            auto_remediation: true,
+            ..Default::default()
+        }).await.unwrap();
+        assert!(!plugin.tier_allows(Feature::AutoRemediation));
+        assert!(plugin.tier_allows(Feature::BasicMonitoring));
+        
+        let err = plugin.set_remediation_handler(NoopRemediation).await.unwrap_err();
+        assert!(matches!(err, Error::FeatureUnavailable { feature: Feature::AutoRemediation, .. }));
+        
+        let id = plugin.add_model("broken".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.1).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(plugin.state.read().await.monitored_models[&id].last_remediation.is_none());
+    }
+
+    /// Never finishes within any reasonable timeout
+    struct HungHandler;
+
+    #[async_trait]
+    impl RemediationHandler for HungHandler {
+        async fn remediate(&self, _model: &MonitoredModel) -> Result<RemediationOutcome> {
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+            Ok(RemediationOutcome::Resolved)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_hung_handler_times_out() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            operation_timeout_seconds: 5,
+            ..Default::default()
+        }).await.unwrap();
+        plugin.set_remediation_handler(HungHandler).await.unwrap();
+        let id = plugin.add_model("hung".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.1).await.unwrap();
+        tokio::time::sleep(Duration::from_secs(6)).await;
+        
+        let record = wait_for_remediation(&plugin, id).await;
+        assert_eq!(record.outcome, RemediationOutcome::TimedOut);
+        assert!(plugin.remediating.lock().unwrap().is_empty());
+    }
+}
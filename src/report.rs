@@ -0,0 +1,170 @@
+//! Human-readable model reports for on-call engineers
+
+use chrono::{DateTime, Utc};
+use std::fmt::Write;
+use uuid::Uuid;
+
+use crate::analytics::{recent_anomalies, slope, trend};
+use crate::{Error, ModelPerformanceMonitoringPlugin, MonitoredModel, PluginConfig, Trend};
+
+/// Samples scanned for anomalies in a report
+const ANOMALY_WINDOW: usize = 10;
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Multi-line summary of a model: status, score, trend, uptime, last
+    /// check age, recent anomalies, tags and notes
+    pub async fn describe_model(&self, id: Uuid) -> Result<String, Error> {
+        let state = self.state.load();
+        let model = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?;
+        Ok(describe(model, &state.config, self.now()))
+    }
+}
+
+/// Render the report for `model` as of `now`
+pub(crate) fn describe(model: &MonitoredModel, config: &PluginConfig, now: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    let mut flags = Vec::new();
+    if model.paused {
+        flags.push("paused");
+    }
+    if model.deleted_at.is_some() {
+        flags.push("deleted");
+    }
+    if model.in_warmup(config, now) {
+        flags.push("warming up");
+    }
+    let flags = if flags.is_empty() { String::new() } else { format!(" [{}]", flags.join(", ")) };
+    
+    let _ = writeln!(out, "Model '{}' ({}): {:?}{}", model.name, model.model_type, model.status, flags);
+    let _ = writeln!(out, "  id:         {}", model.id);
+    let _ = writeln!(
+        out,
+        "  score:      {:.3} (threshold {:.2})",
+        model.performance_score,
+        model.effective_threshold(config)
+    );
+    let trend_line = match (trend(model, config), slope(model, config.trend_window)) {
+        (Some(trend), Some(slope)) => format!("{:?} ({:+.4} per sample)", trend, slope),
+        _ => format!("{:?} (not enough history)", Trend::Unknown),
+    };
+    let _ = writeln!(out, "  trend:      {}", trend_line);
+    let _ = writeln!(out, "  uptime:     {:.1}%", model.uptime_ratio(now) * 100.0);
+    let _ = writeln!(out, "  last check: {} ago", format_age(now - model.last_check));
+    let tags = if model.tags.is_empty() {
+        "none".to_string()
+    } else {
+        model.tags.iter().cloned().collect::<Vec<_>>().join(", ")
+    };
+    let _ = writeln!(out, "  tags:       {}", tags);
+    
+    let anomalies = recent_anomalies(model, config, ANOMALY_WINDOW);
+    if anomalies.is_empty() {
+        let _ = writeln!(out, "  anomalies:  none in the last {} samples", ANOMALY_WINDOW);
+    } else {
+        let _ = writeln!(out, "  anomalies:");
+        for (at, report) in anomalies {
+            let _ = writeln!(
+                out,
+                "    {} score {:.3} (z {:+.1}, mean {:.3})",
+                at.format("%Y-%m-%d %H:%M:%S UTC"),
+                report.latest,
+                report.zscore,
+                report.mean
+            );
+        }
+    }
+    
+    if model.notes.is_empty() {
+        let _ = writeln!(out, "  notes:      none");
+    } else {
+        let _ = writeln!(out, "  notes:");
+        for note in &model.notes {
+            let _ = writeln!(out, "    {} {}: {}", note.timestamp.format("%Y-%m-%d %H:%M UTC"), note.author, note.text);
+        }
+    }
+    
+    out
+}
+
+/// Coarse age such as "45s", "12m 5s", "3h 20m" or "2d 4h"
+fn format_age(age: chrono::Duration) -> String {
+    let secs = age.num_seconds().max(0);
+    let (days, hours, minutes, seconds) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds),
+        (0, 0, _) => format!("{}m {}s", minutes, seconds),
+        (0, _, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h", days, hours),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_model;
+    use crate::{ModelStatus, Note};
+    use chrono::Duration;
+
+    #[test]
+    fn test_describe_populated_model() {
+        let now = Utc::now();
+        let mut model = sample_model("ranker", 0.42, ModelStatus::Critical);
+        model.created_at = now - Duration::days(2);
+        model.last_check = now - Duration::seconds(185);
+        model.tags = ["production".to_string(), "search".to_string()].into();
+        for i in 0..12 {
+            model.history.push_back((now - Duration::minutes(60 - i), 0.9 - i as f64 * 0.001));
+        }
+        model.history.push_back((now - Duration::minutes(3), 0.42));
+        model.notes.push(Note {
+            timestamp: now,
+            author: "alice".to_string(),
+            text: "Rolled back to v12".to_string(),
+        });
+        model.paused = true;
+        
+        let report = describe(&model, &PluginConfig::default(), now);
+        
+        assert!(report.starts_with("Model 'ranker' (llm): Critical [paused]\n"));
+        assert!(report.contains("score:      0.420 (threshold 0.85)"));
+        assert!(report.contains("trend:      Declining ("));
+        assert!(report.contains("uptime:     "));
+        assert!(report.contains("last check: 3m 5s ago"));
+        assert!(report.contains("tags:       production, search"));
+        assert!(report.contains("score 0.420 (z -"));
+        assert!(report.contains("alice: Rolled back to v12"));
+    }
+
+    #[test]
+    fn test_describe_new_model() {
+        let now = Utc::now();
+        let model = sample_model("fresh", 0.0, ModelStatus::Unknown);
+        
+        let report = describe(&model, &PluginConfig::default(), now);
+        
+        assert!(report.contains("trend:      Unknown (not enough history)"));
+        assert!(report.contains("tags:       none"));
+        assert!(report.contains("anomalies:  none"));
+        assert!(report.contains("notes:      none"));
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(Duration::seconds(45)), "45s");
+        assert_eq!(format_age(Duration::seconds(725)), "12m 5s");
+        assert_eq!(format_age(Duration::minutes(200)), "3h 20m");
+        assert_eq!(format_age(Duration::hours(52)), "2d 4h");
+        assert_eq!(format_age(Duration::seconds(-5)), "0s");
+    }
+
+    #[tokio::test]
+    async fn test_describe_model_by_id() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("ranker".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        let report = plugin.describe_model(id).await.unwrap();
+        assert!(report.contains("Model 'ranker' (llm): Healthy"));
+        assert!(matches!(plugin.describe_model(Uuid::new_v4()).await, Err(Error::ModelNotFound(_))));
+    }
+}
@@ -0,0 +1,92 @@
+//! JSON Schema documents for the plugin's serialized types
+//!
+//! Enabled with the `schema` feature. Field descriptions are taken from the
+//! types' doc comments.
+
+use schemars::{schema_for, JsonSchema, Schema};
+use std::collections::BTreeMap;
+
+use crate::{
+    AggregateAlert, Alert, AlertFormat, AnomalyReport, ArchivedModel, ConfigChange, DriftReport, Feature,
+    HealthSummary, ModelComparison, ModelScore, ModelStatus, ModelType, MonitoredModel, Note, PluginConfig,
+    PluginState, PricingTier, PricingTierName, RemediationOutcome, RemediationRecord, SignedAlert, StateEvent,
+    SystemMetrics, Trend,
+};
+
+fn schema<T: JsonSchema>() -> (&'static str, Schema) {
+    let name = std::any::type_name::<T>().rsplit("::").next().unwrap_or_default();
+    (name, schema_for!(T))
+}
+
+/// Drop a field's `default`, for defaults that vary between runs (e.g. "now")
+pub(crate) fn without_default(schema: &mut Schema) {
+    schema.remove("default");
+}
+
+/// One JSON Schema per public serde type, keyed by type name.
+///
+/// Keys are sorted so the output is stable across runs.
+pub fn json_schemas() -> BTreeMap<&'static str, Schema> {
+    BTreeMap::from([
+        schema::<AggregateAlert>(),
+        schema::<Alert>(),
+        schema::<AlertFormat>(),
+        schema::<AnomalyReport>(),
+        schema::<ArchivedModel>(),
+        schema::<ConfigChange>(),
+        schema::<DriftReport>(),
+        schema::<Feature>(),
+        schema::<HealthSummary>(),
+        schema::<ModelComparison>(),
+        schema::<ModelScore>(),
+        schema::<ModelStatus>(),
+        schema::<ModelType>(),
+        schema::<MonitoredModel>(),
+        schema::<Note>(),
+        schema::<PluginConfig>(),
+        schema::<PluginState>(),
+        schema::<PricingTier>(),
+        schema::<PricingTierName>(),
+        schema::<RemediationOutcome>(),
+        schema::<RemediationRecord>(),
+        schema::<SignedAlert>(),
+        schema::<StateEvent>(),
+        schema::<SystemMetrics>(),
+        schema::<Trend>(),
+    ])
+}
+
+/// All schemas as a single pretty-printed JSON object
+pub fn json_schema_document() -> String {
+    serde_json::to_string_pretty(&json_schemas()).expect("schemas serialize to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_public_type_has_a_schema() {
+        let schemas = json_schemas();
+        assert_eq!(schemas.len(), 25);
+        for (name, schema) in &schemas {
+            assert_eq!(schema.get("title").and_then(|t| t.as_str()), Some(*name));
+        }
+    }
+
+    #[test]
+    fn test_schema_includes_field_descriptions() {
+        let schemas = json_schemas();
+        let config = schemas["PluginConfig"].as_value();
+        let description = &config["properties"]["history_capacity"]["description"];
+        assert_eq!(description, "Maximum number of score samples kept per model");
+        
+        let model_type = schemas["ModelType"].as_value();
+        assert_eq!(model_type["type"], "string");
+    }
+
+    #[test]
+    fn test_schema_document_is_stable() {
+        assert_eq!(json_schema_document(), json_schema_document());
+    }
+}
@@ -0,0 +1,119 @@
+//! Shard assignment for horizontally scaled deployments
+//!
+//! Several instances can split one fleet by giving each a distinct
+//! `shard = [index, total]`. A model belongs to the shard its id maps to
+//! under jump consistent hashing, which depends only on the id and `total`,
+//! so every instance agrees on the owner without coordinating, and growing
+//! `total` moves as few models as possible.
+
+use uuid::Uuid;
+
+use crate::{ModelPerformanceMonitoringPlugin, PluginConfig};
+
+impl PluginConfig {
+    /// Whether this instance's `shard` owns the model; always true when unsharded
+    pub fn owns_model(&self, id: Uuid) -> bool {
+        match self.shard {
+            Some((index, total)) => shard_of(id, total) == index,
+            None => true,
+        }
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Whether the monitoring loop of this instance checks the model, see
+    /// `PluginConfig::shard`
+    pub async fn owns_model(&self, id: Uuid) -> bool {
+        self.current_config().owns_model(id)
+    }
+}
+
+/// Shard in `0..total` that `id` belongs to
+pub fn shard_of(id: Uuid, total: u32) -> u32 {
+    let bits = id.as_u128();
+    jump_hash((bits >> 64) as u64 ^ bits as u64, total)
+}
+
+/// Jump consistent hash (Lamping & Veach, 2014) of `key` into `0..buckets`
+fn jump_hash(mut key: u64, buckets: u32) -> u32 {
+    let (mut bucket, mut next) = (-1i64, 0i64);
+    while next < i64::from(buckets) {
+        bucket = next;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        next = ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    bucket as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sharded(index: u32, total: u32) -> PluginConfig {
+        PluginConfig {
+            shard: Some((index, total)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_every_model_owned_by_exactly_one_shard() {
+        let shards: Vec<PluginConfig> = (0..3).map(|i| sharded(i, 3)).collect();
+        let mut counts = [0; 3];
+        
+        for _ in 0..3000 {
+            let id = Uuid::new_v4();
+            let owners: Vec<usize> = (0..3).filter(|&i| shards[i].owns_model(id)).collect();
+            assert_eq!(owners.len(), 1, "model {} owned by {:?}", id, owners);
+            counts[owners[0]] += 1;
+        }
+        // Roughly even split
+        assert!(counts.iter().all(|&c| c > 800), "{:?}", counts);
+        assert!(PluginConfig::default().owns_model(Uuid::new_v4()));
+        assert!(sharded(3, 3).validate().is_err());
+    }
+
+    #[test]
+    fn test_shard_assignment_is_stable() {
+        let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        assert_eq!(shard_of(id, 1), 0);
+        assert_eq!(shard_of(id, 3), shard_of(id, 3));
+        assert_eq!(jump_hash(0, 3), 0);
+        
+        // Growing the fleet only moves models to the new shard
+        for _ in 0..1000 {
+            let id = Uuid::new_v4();
+            let (before, after) = (shard_of(id, 3), shard_of(id, 4));
+            assert!(after == before || after == 3);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitoring_loop_checks_only_owned_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(sharded(1, 2)).await.unwrap();
+        let mut ids = Vec::new();
+        for i in 0..20 {
+            ids.push(plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap());
+        }
+        let checked = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = checked.clone();
+        plugin.set_health_check(move |model| log.lock().unwrap().push(model.id)).await;
+        
+        let handle = plugin.start_monitoring();
+        tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+        
+        let checked = checked.lock().unwrap().clone();
+        let mut owned = Vec::new();
+        for id in &ids {
+            if plugin.owns_model(*id).await {
+                owned.push(*id);
+            }
+        }
+        assert!(!owned.is_empty() && owned.len() < ids.len());
+        assert_eq!(checked.len(), owned.len());
+        assert!(checked.iter().all(|id| owned.contains(id)));
+    }
+}
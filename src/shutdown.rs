@@ -0,0 +1,133 @@
+//! Graceful shutdown
+//!
+//! `run` blocks until SIGTERM/Ctrl-C (or `request_shutdown`) and then flushes
+//! state to `config.state_path` before returning.
+
+use tracing::{error, info, warn};
+
+use crate::ModelPerformanceMonitoringPlugin;
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Ask a running `run` call to shut down, as if a signal had arrived
+    pub fn request_shutdown(&self) {
+        self.shutdown_requested.notify_one();
+    }
+    
+    pub(crate) async fn wait_for_shutdown(&self) {
+        tokio::select! {
+            _ = shutdown_signal() => info!("Shutdown signal received"),
+            _ = self.shutdown_requested.notified() => info!("Shutdown requested"),
+        }
+    }
+    
+    /// Resolves once the plugin has begun shutting down. Unlike the
+    /// monitoring loop's signal, every waiter sees it.
+    #[cfg(any(feature = "synthetic", feature = "hot-reload"))]
+    pub(crate) async fn stopping(&self) {
+        let mut stopping = self.stopping.subscribe();
+        let _ = stopping.wait_for(|stopping| *stopping).await;
+    }
+    
+    /// Save state to the configured path, logging rather than failing on error
+    pub(crate) async fn persist_on_shutdown(&self) {
+        let Some(path) = self.current_config().state_path.clone() else {
+            return;
+        };
+        
+        match self.save_state(&path).await {
+            Ok(()) => info!("Saved state to {}", path.display()),
+            Err(e) => error!("Failed to save state on shutdown: {:#}", e),
+        }
+    }
+}
+
+/// Resolves on SIGTERM or Ctrl-C (Ctrl-C only on non-Unix platforms)
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = terminate.recv() => {}
+                    _ = tokio::signal::ctrl_c() => {}
+                }
+                return;
+            }
+            Err(e) => warn!("Could not install SIGTERM handler, falling back to Ctrl-C: {}", e),
+        }
+    }
+    
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        warn!("Could not listen for Ctrl-C: {}", e);
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModelType, PluginConfig};
+
+    #[tokio::test]
+    async fn test_shutdown_persists_state() {
+        let path = std::env::temp_dir().join(format!("adios-mpm-shutdown-{}.json", uuid::Uuid::new_v4()));
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            state_path: Some(path.clone()),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("survivor".to_string(), ModelType::LLM).await.unwrap();
+        
+        let running = plugin.clone();
+        let handle = tokio::spawn(async move { running.run().await });
+        plugin.request_shutdown();
+        handle.await.unwrap().unwrap();
+        
+        let state = plugin.load_state(&path).await.unwrap();
+        assert!(state.monitored_models.contains_key(&id));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restart_restores_state_saved_on_shutdown() {
+        let path = std::env::temp_dir().join(format!("adios-mpm-shutdown-{}.json", uuid::Uuid::new_v4()));
+        let config = PluginConfig {
+            state_path: Some(path.clone()),
+            check_interval_minutes: 7,
+            ..PluginConfig::default()
+        };
+        let plugin = ModelPerformanceMonitoringPlugin::with_config(config.clone()).await.unwrap();
+        let id = plugin.add_model("survivor".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        let running = plugin.clone();
+        let handle = tokio::spawn(async move { running.run().await });
+        plugin.request_shutdown();
+        handle.await.unwrap().unwrap();
+        
+        let restarted = ModelPerformanceMonitoringPlugin::with_config(config).await.unwrap();
+        let model = restarted.get_model(id).await.unwrap();
+        assert_eq!(model.name, "survivor");
+        assert_eq!(model.performance_score, 0.9);
+        assert_eq!(restarted.current_config().check_interval_minutes, 7);
+        assert_eq!(restarted.snapshot().await.system_metrics.total_models, 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_survives_persistence_failure() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            state_path: Some("/nonexistent-dir/state.json".into()),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        
+        let running = plugin.clone();
+        let handle = tokio::spawn(async move { running.run().await });
+        plugin.request_shutdown();
+        
+        assert!(handle.await.unwrap().is_ok());
+    }
+}
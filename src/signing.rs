@@ -0,0 +1,152 @@
+//! Alert payload signing
+//!
+//! With `alert_signing_secret` set, every webhook delivery carries an
+//! HMAC-SHA256 of the request body in the `X-Adios-Signature` header as
+//! `sha256=<hex>`. Receivers check it with [`verify_payload`]. Alerts handed
+//! on as raw JSON rather than over the webhook can carry the same signature
+//! in a `signature` field via [`SignedAlert`].
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::Alert;
+
+/// Header carrying the body signature on webhook deliveries
+pub const SIGNATURE_HEADER: &str = "X-Adios-Signature";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Shared secret for alert signatures.
+///
+/// Never printed by `Debug` and never serialized, so it stays out of logs
+/// and saved state; it can only be read from a config file.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct AlertSecret(String);
+
+impl AlertSecret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+    
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(self.0.as_bytes()).expect("HMAC accepts keys of any length")
+    }
+}
+
+impl std::fmt::Debug for AlertSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AlertSecret(<redacted>)")
+    }
+}
+
+/// Hex-encoded HMAC-SHA256 of `body` under `secret`, as sent after `sha256=`
+pub fn sign_payload(secret: &AlertSecret, body: &[u8]) -> String {
+    let mut mac = secret.mac();
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Whether `signature` is the signature of `body` under `secret`.
+///
+/// Accepts the bare hex digest or the `sha256=` header form, and compares in
+/// constant time.
+pub fn verify_payload(secret: &AlertSecret, body: &[u8], signature: &str) -> bool {
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let Ok(expected) = hex::decode(signature) else {
+        return false;
+    };
+    let mut mac = secret.mac();
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// An alert with the signature of its raw JSON encoding alongside it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SignedAlert {
+    #[serde(flatten)]
+    pub alert: Alert,
+    pub signature: String,
+}
+
+impl SignedAlert {
+    /// Sign the `AlertFormat::Raw` encoding of `alert`
+    pub fn new(alert: Alert, secret: &AlertSecret) -> Self {
+        let signature = sign_payload(secret, unsigned_body(&alert).as_bytes());
+        Self { alert, signature }
+    }
+    
+    /// Whether the signature matches the alert it was sent with
+    pub fn verify(&self, secret: &AlertSecret) -> bool {
+        verify_payload(secret, unsigned_body(&self.alert).as_bytes(), &self.signature)
+    }
+}
+
+fn unsigned_body(alert: &Alert) -> String {
+    alert.render(crate::AlertFormat::Raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, mock_http_server};
+    use crate::{ModelPerformanceMonitoringPlugin, ModelStatus, PluginConfig};
+
+    #[test]
+    fn test_tampered_payload_fails_verification() {
+        let secret = AlertSecret::new("s3cret");
+        let body = br#"{"model_id":"a","new_status":"Critical"}"#;
+        let signature = sign_payload(&secret, body);
+        
+        assert!(verify_payload(&secret, body, &signature));
+        assert!(verify_payload(&secret, body, &format!("sha256={}", signature)));
+        assert!(!verify_payload(&secret, br#"{"model_id":"a","new_status":"Healthy"}"#, &signature));
+        assert!(!verify_payload(&AlertSecret::new("other"), body, &signature));
+        assert!(!verify_payload(&secret, body, "not hex"));
+    }
+
+    #[test]
+    fn test_signed_alert_field() {
+        let secret = AlertSecret::new("s3cret");
+        let model = test_support::sample_model("ranker", 0.42, ModelStatus::Critical);
+        let signed = SignedAlert::new(Alert::new(&model, ModelStatus::Healthy), &secret);
+        
+        let json = serde_json::to_string(&signed).unwrap();
+        let mut received: SignedAlert = serde_json::from_str(&json).unwrap();
+        assert!(received.verify(&secret));
+        
+        received.alert.score = 0.99;
+        assert!(!received.verify(&secret));
+    }
+
+    #[test]
+    fn test_secret_stays_out_of_logs_and_state() {
+        let config: PluginConfig = toml::from_str(r#"alert_signing_secret = "s3cret""#).unwrap();
+        
+        assert!(config.alert_signing_secret.is_some());
+        assert!(!format!("{:?}", config).contains("s3cret"));
+        assert!(!serde_json::to_string(&config).unwrap().contains("s3cret"));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery_is_signed() {
+        let (addr, mut requests) = mock_http_server(200).await;
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            alert_webhook: Some(format!("http://{}/hook", addr)),
+            alert_signing_secret: Some(AlertSecret::new("s3cret")),
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let id = plugin.add_model("signed".to_string(), "llm".to_string()).await.unwrap();
+        
+        plugin.record_performance(id, 0.8).await.unwrap();
+        
+        let request = requests.recv().await.unwrap();
+        let signature = request.header(SIGNATURE_HEADER).unwrap();
+        assert!(signature.starts_with("sha256="));
+        assert!(verify_payload(&AlertSecret::new("s3cret"), request.body.as_bytes(), signature));
+    }
+}
@@ -0,0 +1,217 @@
+//! Soft deletion
+//!
+//! A soft-deleted model stops being checked but stays in the state for
+//! `soft_delete_ttl_hours`, so a mistaken deletion can be restored. After
+//! that the monitoring loop purges it through `remove_model`, which moves
+//! it to the archive. Until then it is left out of metrics, summaries and
+//! dependency cascades, and does not count against the tier's capacity.
+
+use chrono::Duration;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{dependencies, recompute_metrics, Error, ModelPerformanceMonitoringPlugin, StateEvent};
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Mark a model deleted: it is no longer checked and rejects scores.
+    /// Deleting an already deleted model keeps its original deletion time.
+    pub async fn soft_delete(&self, id: Uuid) -> Result<(), Error> {
+        self.set_deleted(id, true).await
+    }
+    
+    /// Undo a soft deletion that has not been purged yet. The restored model
+    /// counts against the tier's capacity again, so this fails with
+    /// `CapacityExceeded` if the tier is full.
+    pub async fn restore(&self, id: Uuid) -> Result<(), Error> {
+        self.set_deleted(id, false).await
+    }
+    
+    /// Ids of the soft-deleted models still awaiting purge
+    pub async fn deleted_models(&self) -> Vec<Uuid> {
+        let state = self.state.load();
+        state
+            .monitored_models
+            .values()
+            .filter(|m| m.deleted_at.is_some())
+            .map(|m| m.id)
+            .collect()
+    }
+    
+    async fn set_deleted(&self, id: Uuid, deleted: bool) -> Result<(), Error> {
+        let now = self.now();
+        let mut state = self.state.write().await;
+        let was_deleted = state.monitored_models.get(&id).ok_or(Error::ModelNotFound(id))?.deleted_at.is_some();
+        if was_deleted && !deleted {
+            let tier = state.config.tier;
+            if let Some(limit) = tier.max_models().filter(|&limit| state.live_models() >= limit) {
+                return Err(Error::CapacityExceeded { tier, limit });
+            }
+        }
+        let model = state.model_mut(id)?;
+        if deleted {
+            model.deleted_at.get_or_insert(now);
+        } else {
+            model.deleted_at = None;
+        }
+        // A deleted upstream no longer cascades, and deleted models leave the metrics
+        let config = state.config.clone();
+        let transitions = dependencies::propagate_dependencies(&mut state.monitored_models, &config, now);
+        recompute_metrics(&mut state);
+        drop(state);
+        
+        info!(model_id = %id, "{} model", if deleted { "Soft-deleted" } else { "Restored" });
+        self.config_changed.notify_waiters();
+        self.emit(StateEvent::ModelUpdated(id));
+        self.emit(StateEvent::MetricsUpdated);
+        self.finish_transitions(&config, transitions);
+        Ok(())
+    }
+    
+    /// Permanently remove soft-deleted models older than
+    /// `soft_delete_ttl_hours`, returning their ids
+    pub async fn purge_deleted(&self) -> Vec<Uuid> {
+        let expired: Vec<Uuid> = {
+            let state = self.state.read().await;
+            let cutoff = self.now() - Duration::hours(i64::from(state.config.soft_delete_ttl_hours));
+            state
+                .monitored_models
+                .values()
+                .filter(|m| m.deleted_at.is_some_and(|at| at <= cutoff))
+                .map(|m| m.id)
+                .collect()
+        };
+        
+        let mut purged = Vec::new();
+        for id in expired {
+            // May already be gone if removed concurrently
+            if self.remove_model(id).await.is_ok() {
+                purged.push(id);
+            }
+        }
+        if !purged.is_empty() {
+            info!("Purged {} soft-deleted models", purged.len());
+        }
+        purged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModelStatus, ModelType, PluginConfig, PricingTierName};
+    use chrono::Utc;
+
+    async fn backdate_deletion(plugin: &ModelPerformanceMonitoringPlugin, id: Uuid, hours: i64) {
+        let mut state = plugin.state.write().await;
+        let model = state.model_mut(id).unwrap();
+        model.deleted_at = Some(Utc::now() - Duration::hours(hours));
+    }
+
+    #[tokio::test]
+    async fn test_restore_before_ttl() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("oops".to_string(), ModelType::LLM).await.unwrap();
+        
+        plugin.soft_delete(id).await.unwrap();
+        assert_eq!(plugin.deleted_models().await, vec![id]);
+        assert!(matches!(plugin.record_performance(id, 0.9).await, Err(Error::ModelDeleted(_))));
+        
+        backdate_deletion(&plugin, id, 71).await;
+        assert!(plugin.purge_deleted().await.is_empty());
+        
+        plugin.restore(id).await.unwrap();
+        assert!(plugin.deleted_models().await.is_empty());
+        plugin.record_performance(id, 0.9).await.unwrap();
+        
+        // A restored model is never purged
+        assert!(plugin.purge_deleted().await.is_empty());
+        assert!(plugin.state.read().await.monitored_models.contains_key(&id));
+    }
+
+    #[tokio::test]
+    async fn test_purge_after_ttl() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let expired = plugin.add_model("expired".to_string(), ModelType::LLM).await.unwrap();
+        let recent = plugin.add_model("recent".to_string(), ModelType::LLM).await.unwrap();
+        plugin.soft_delete(expired).await.unwrap();
+        plugin.soft_delete(recent).await.unwrap();
+        backdate_deletion(&plugin, expired, 73).await;
+        
+        // Deleting again doesn't reset the clock
+        plugin.soft_delete(expired).await.unwrap();
+        
+        assert_eq!(plugin.purge_deleted().await, vec![expired]);
+        assert_eq!(plugin.deleted_models().await, vec![recent]);
+        assert!(plugin.list_archived().await.iter().any(|a| a.model.id == expired));
+        assert!(matches!(plugin.restore(expired).await, Err(Error::ModelNotFound(_))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_monitoring_loop_purges_and_skips_deleted() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let kept = plugin.add_model("kept".to_string(), ModelType::LLM).await.unwrap();
+        let deleted = plugin.add_model("deleted".to_string(), ModelType::LLM).await.unwrap();
+        plugin.soft_delete(kept).await.unwrap();
+        plugin.soft_delete(deleted).await.unwrap();
+        backdate_deletion(&plugin, deleted, 100).await;
+        
+        let handle = plugin.start_monitoring();
+        tokio::time::sleep(std::time::Duration::from_secs(6 * 60)).await;
+        
+        let state = plugin.state.read().await;
+        assert!(!state.monitored_models.contains_key(&deleted));
+        assert_eq!(state.monitored_models[&kept].history.len(), 0);
+        drop(state);
+        assert!(plugin.stale_models(std::time::Duration::ZERO).await.is_empty());
+        
+        plugin.stop_monitoring();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_deleted_models_free_capacity() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.update_config(PluginConfig {
+            tier: PricingTierName::Starter,
+            ..PluginConfig::default()
+        }).await.unwrap();
+        let limit = PricingTierName::Starter.max_models().unwrap();
+        let mut ids = Vec::new();
+        for i in 0..limit {
+            ids.push(plugin.add_model(format!("model-{}", i), ModelType::LLM).await.unwrap());
+        }
+        assert!(matches!(plugin.add_model("extra".to_string(), ModelType::LLM).await, Err(Error::CapacityExceeded { .. })));
+        
+        plugin.soft_delete(ids[0]).await.unwrap();
+        let replacement = plugin.add_model("replacement".to_string(), ModelType::LLM).await.unwrap();
+        
+        // With the replacement in place the tier is full again, so the deleted model can't come back
+        assert!(matches!(plugin.restore(ids[0]).await, Err(Error::CapacityExceeded { .. })));
+        plugin.soft_delete(replacement).await.unwrap();
+        assert!(plugin.bulk_add_models(vec![crate::ModelSpec::new("bulk", ModelType::LLM)]).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_exclude_deleted_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let kept = plugin.add_model("kept".to_string(), ModelType::LLM).await.unwrap();
+        let deleted = plugin.add_model("deleted".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(kept, 0.9).await.unwrap();
+        plugin.record_performance(deleted, 0.1).await.unwrap();
+        
+        plugin.soft_delete(deleted).await.unwrap();
+        
+        let metrics = plugin.snapshot().await.system_metrics;
+        assert_eq!(metrics.total_models, 1);
+        assert_eq!(metrics.degraded_models, 0);
+        assert_eq!(metrics.average_performance, 0.9);
+        let summary = plugin.health_summary(None).await;
+        assert_eq!((summary.total_models, summary.critical_models), (1, 0));
+        assert_eq!(plugin.system_health_score().await, 0.9);
+        assert_eq!(plugin.score_percentiles(&[0.0]).await[&0], 0.9);
+        assert_eq!(plugin.count_by_status().await[&ModelStatus::Critical], 0);
+        
+        plugin.restore(deleted).await.unwrap();
+        assert_eq!(plugin.snapshot().await.system_metrics.total_models, 2);
+    }
+}
@@ -0,0 +1,209 @@
+//! Lock-free reads of the plugin state
+//!
+//! Writers still serialize on a `RwLock`, but every write guard publishes a
+//! copy of the state it modified when it is released. Hot readers such as
+//! dashboards and the monitoring loop load that published copy without
+//! taking the lock, so they never wait behind a writer; they see the state
+//! as of the last completed write.
+//!
+//! The copy is shallow: models are held behind `Arc`s and copied on write,
+//! so publishing after a score is recorded clones only the pointers and
+//! the one model that changed, not every model's history.
+//!
+//! The config is also published on its own, swapped only when it is
+//! replaced, so paths that need nothing but the config neither lock nor
+//! copy the model map.
+
+use arc_swap::ArcSwap;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use uuid::Uuid;
+
+use crate::{Error, MonitoredModel, PluginConfig, PluginState};
+
+pub(crate) struct StateCell {
+    lock: RwLock<PluginState>,
+    published: ArcSwap<PluginState>,
+    config: ArcSwap<PluginConfig>,
+}
+
+impl StateCell {
+    pub(crate) fn new(state: PluginState) -> Self {
+        Self {
+            published: ArcSwap::from_pointee(state.clone()),
+            config: ArcSwap::from_pointee(state.config.clone()),
+            lock: RwLock::new(state),
+        }
+    }
+    
+    /// Lock the live state for reading, waiting for any writer
+    pub(crate) async fn read(&self) -> RwLockReadGuard<'_, PluginState> {
+        self.lock.read().await
+    }
+    
+    /// Lock the live state for writing; changes are published on release
+    pub(crate) async fn write(&self) -> StateWriteGuard<'_> {
+        StateWriteGuard {
+            guard: self.lock.write().await,
+            published: &self.published,
+            published_config: &self.config,
+            modified: false,
+        }
+    }
+    
+    /// The state as of the last completed write, without locking
+    pub(crate) fn load(&self) -> Arc<PluginState> {
+        self.published.load_full()
+    }
+    
+    /// The config as of the last `set_config`, without locking
+    pub(crate) fn config(&self) -> Arc<PluginConfig> {
+        self.config.load_full()
+    }
+}
+
+/// Write access to the state that publishes it, if modified, when dropped
+pub(crate) struct StateWriteGuard<'a> {
+    guard: RwLockWriteGuard<'a, PluginState>,
+    published: &'a ArcSwap<PluginState>,
+    published_config: &'a ArcSwap<PluginConfig>,
+    modified: bool,
+}
+
+impl StateWriteGuard<'_> {
+    /// Replace the config, publishing it right away. Config changes must go
+    /// through here for `StateCell::config` to see them.
+    pub(crate) fn set_config(&mut self, config: PluginConfig) {
+        self.published_config.store(Arc::new(config.clone()));
+        self.guard.config = config;
+        self.modified = true;
+    }
+    
+    /// Mutable access to one model. An unknown id fails with `ModelNotFound`
+    /// without marking the state modified, so nothing is published for it.
+    pub(crate) fn model_mut(&mut self, id: Uuid) -> Result<&mut MonitoredModel, Error> {
+        let model = self.guard.monitored_models.get_mut(&id).ok_or(Error::ModelNotFound(id))?;
+        self.modified = true;
+        Ok(Arc::make_mut(model))
+    }
+}
+
+impl Deref for StateWriteGuard<'_> {
+    type Target = PluginState;
+    
+    fn deref(&self) -> &PluginState {
+        &self.guard
+    }
+}
+
+impl DerefMut for StateWriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut PluginState {
+        self.modified = true;
+        &mut self.guard
+    }
+}
+
+impl Drop for StateWriteGuard<'_> {
+    fn drop(&mut self) {
+        // Published while the lock is still held, so writes are seen in order
+        if self.modified {
+            self.published.store(Arc::new(self.guard.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelPerformanceMonitoringPlugin;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn test_reads_see_completed_writes() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        plugin.record_performance(id, 0.4).await.unwrap();
+        
+        assert_eq!(plugin.get_model(id).await.unwrap().performance_score, 0.4);
+        assert_eq!(plugin.list_models(None).await.len(), 1);
+        assert_eq!(plugin.snapshot().await.system_metrics.total_models, 1);
+        
+        // Unmodified write guards publish nothing
+        let before = plugin.state.load();
+        drop(plugin.state.write().await);
+        assert!(Arc::ptr_eq(&before, &plugin.state.load()));
+        
+        // Nor do writes that fail before changing anything
+        let unknown = uuid::Uuid::new_v4();
+        assert!(plugin.record_performance(unknown, 0.5).await.is_err());
+        assert!(plugin.record_performance(id, 1.5).await.is_err());
+        assert!(plugin.record_performance_batch(vec![(unknown, 0.5)]).await[0].is_err());
+        assert!(plugin.add_tag(unknown, "prod").await.is_err());
+        plugin.pause_model(id).await.unwrap();
+        let paused = plugin.state.load();
+        assert!(plugin.record_performance(id, 0.5).await.is_err());
+        assert!(Arc::ptr_eq(&paused, &plugin.state.load()));
+    }
+
+    #[tokio::test]
+    async fn test_publishing_shares_unchanged_models() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), "llm".to_string()).await.unwrap();
+        let b = plugin.add_model("b".to_string(), "llm".to_string()).await.unwrap();
+        let before = plugin.state.load();
+        
+        plugin.record_performance(a, 0.4).await.unwrap();
+        
+        let after = plugin.state.load();
+        assert!(Arc::ptr_eq(&before.monitored_models[&b], &after.monitored_models[&b]));
+        assert!(!Arc::ptr_eq(&before.monitored_models[&a], &after.monitored_models[&a]));
+        assert_eq!(before.monitored_models[&a].performance_score, 1.0);
+        assert_eq!(after.monitored_models[&a].performance_score, 0.4);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_reads_do_not_stall_behind_writers() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut ids = Vec::new();
+        for i in 0..200 {
+            ids.push(plugin.add_model(format!("model-{}", i), "llm".to_string()).await.unwrap());
+        }
+        
+        // Write-heavy background load
+        let writer = plugin.clone();
+        let writes = tokio::spawn(async move {
+            let deadline = Instant::now() + Duration::from_millis(500);
+            let mut count = 0;
+            while Instant::now() < deadline {
+                let batch: Vec<_> = ids.iter().map(|id| (*id, 0.5 + (count % 50) as f64 / 100.0)).collect();
+                writer.record_performance_batch(batch).await;
+                count += 1;
+            }
+            count
+        });
+        // A writer that holds the lock until every read below has finished
+        let (locked_tx, locked) = tokio::sync::oneshot::channel();
+        let (release, release_rx) = tokio::sync::oneshot::channel::<()>();
+        let holder = plugin.clone();
+        let held = tokio::spawn(async move {
+            let _guard = holder.state.write().await;
+            locked_tx.send(()).unwrap();
+            let _ = release_rx.await;
+        });
+        locked.await.unwrap();
+        
+        // Reads that waited for the lock would never finish, so the timeout is only a backstop
+        let reads = async {
+            for _ in 0..100 {
+                assert_eq!(plugin.list_models(None).await.len(), 200);
+                assert_eq!(plugin.snapshot().await.monitored_models.len(), 200);
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(10), reads).await.expect("reads stalled behind a writer");
+        
+        release.send(()).unwrap();
+        held.await.unwrap();
+        writes.await.unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+//! Pluggable status derivation
+//!
+//! `record_performance` asks the configured `StatusStrategy` which status a
+//! score maps to. `ThresholdStrategy` is the default.
+
+use std::sync::Arc;
+
+use crate::{ModelPerformanceMonitoringPlugin, ModelStatus, MonitoredModel, PluginConfig};
+
+/// Policy mapping a model's score to a status
+pub trait StatusStrategy: Send + Sync {
+    /// `score` is the model's `status_score`, i.e. already smoothed
+    fn derive(&self, score: f64, cfg: &PluginConfig, model: &MonitoredModel) -> ModelStatus;
+}
+
+/// Bands relative to the model's effective threshold, see `ModelStatus::from_score`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThresholdStrategy;
+
+impl StatusStrategy for ThresholdStrategy {
+    fn derive(&self, score: f64, cfg: &PluginConfig, model: &MonitoredModel) -> ModelStatus {
+        ModelStatus::from_score(score, model.effective_threshold(cfg))
+    }
+}
+
+/// Fixed bands, independent of `performance_threshold`: scores at or above
+/// `healthy` are Healthy, then Degraded down to `degraded`, and Critical
+/// below that. Offline is left to missed checks; no score maps to it.
+#[derive(Debug, Clone, Copy)]
+pub struct BandStrategy {
+    pub healthy: f64,
+    pub degraded: f64,
+}
+
+impl Default for BandStrategy {
+    fn default() -> Self {
+        Self {
+            healthy: 0.9,
+            degraded: 0.75,
+        }
+    }
+}
+
+impl StatusStrategy for BandStrategy {
+    fn derive(&self, score: f64, _cfg: &PluginConfig, _model: &MonitoredModel) -> ModelStatus {
+        if score >= self.healthy {
+            ModelStatus::Healthy
+        } else if score >= self.degraded {
+            ModelStatus::Degraded
+        } else {
+            ModelStatus::Critical
+        }
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Replace the policy used to derive status from recorded scores.
+    ///
+    /// Applies to scores recorded from now on; existing statuses are kept.
+    pub async fn set_status_strategy<S>(&self, strategy: S)
+    where
+        S: StatusStrategy + 'static,
+    {
+        *self.status_strategy.write().await = Arc::new(strategy);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_model;
+
+    #[test]
+    fn test_strategies_on_same_scores() {
+        let cfg = PluginConfig::default();
+        let model = sample_model("banded", 1.0, ModelStatus::Unknown);
+        let threshold = ThresholdStrategy;
+        let bands = BandStrategy::default();
+        
+        let cases = [
+            (0.95, ModelStatus::Healthy, ModelStatus::Healthy),
+            (0.87, ModelStatus::Healthy, ModelStatus::Degraded),
+            (0.7, ModelStatus::Degraded, ModelStatus::Critical),
+            (0.4, ModelStatus::Critical, ModelStatus::Critical),
+            (0.0, ModelStatus::Critical, ModelStatus::Critical),
+        ];
+        for (score, by_threshold, by_bands) in cases {
+            assert_eq!(threshold.derive(score, &cfg, &model), by_threshold, "threshold @ {}", score);
+            assert_eq!(bands.derive(score, &cfg, &model), by_bands, "bands @ {}", score);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_strategy_drives_record_performance() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("banded".to_string(), "llm".to_string()).await.unwrap();
+        assert_eq!(plugin.record_performance(id, 0.87).await.unwrap(), ModelStatus::Healthy);
+        
+        plugin.set_status_strategy(BandStrategy::default()).await;
+        assert_eq!(plugin.record_performance(id, 0.87).await.unwrap(), ModelStatus::Degraded);
+        assert_eq!(plugin.record_performance(id, 0.3).await.unwrap(), ModelStatus::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_lowest_band_remediates_and_cascades() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        plugin.set_status_strategy(BandStrategy::default()).await;
+        let upstream = plugin.add_model("upstream".to_string(), "llm".to_string()).await.unwrap();
+        let downstream = plugin.add_model("downstream".to_string(), "llm".to_string()).await.unwrap();
+        plugin.set_dependencies(downstream, vec![upstream]).await.unwrap();
+        
+        plugin.record_performance(upstream, 0.1).await.unwrap();
+        plugin.record_performance(downstream, 0.95).await.unwrap();
+        
+        assert_eq!(plugin.get_model(downstream).await.unwrap().status, ModelStatus::Degraded);
+        for _ in 0..100 {
+            if plugin.get_model(upstream).await.unwrap().last_remediation.is_some() {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("lowest band was never remediated");
+    }
+}
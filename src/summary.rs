@@ -0,0 +1,494 @@
+//! Aggregate health reporting
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin, ModelStatus, MonitoredModel};
+
+/// Fleet-wide health snapshot for dashboard headers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HealthSummary {
+    pub total_models: usize,
+    pub healthy_models: usize,
+    pub degraded_models: usize,
+    pub critical_models: usize,
+    pub offline_models: usize,
+    pub unknown_models: usize,
+    
+    /// Paused models, which count towards `total_models` only
+    pub paused_models: usize,
+    pub average_performance: f64,
+    
+    /// Lowest-scoring model, `None` when there are no models
+    pub worst_performer: Option<ModelScore>,
+    
+    /// Oldest `last_check` across all models, `None` when there are no models
+    pub oldest_check: Option<DateTime<Utc>>,
+}
+
+/// A model's identity paired with its current score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModelScore {
+    pub id: Uuid,
+    pub name: String,
+    pub score: f64,
+}
+
+impl HealthSummary {
+    /// Aggregate the given models into a summary, leaving out soft-deleted
+    /// ones. `Unknown` models have no real score yet, so they don't count
+    /// towards `average_performance` or `worst_performer`.
+    pub fn from_models<'a>(models: impl IntoIterator<Item = &'a MonitoredModel>) -> Self {
+        let mut summary = HealthSummary {
+            total_models: 0,
+            healthy_models: 0,
+            degraded_models: 0,
+            critical_models: 0,
+            offline_models: 0,
+            unknown_models: 0,
+            paused_models: 0,
+            average_performance: 0.0,
+            worst_performer: None,
+            oldest_check: None,
+        };
+        
+        let (mut score_sum, mut scored) = (0.0, 0);
+        for model in models.into_iter().filter(|m| m.deleted_at.is_none()) {
+            summary.total_models += 1;
+            if model.paused {
+                summary.paused_models += 1;
+                continue;
+            }
+            match model.status {
+                ModelStatus::Healthy => summary.healthy_models += 1,
+                ModelStatus::Degraded => summary.degraded_models += 1,
+                ModelStatus::Critical => summary.critical_models += 1,
+                ModelStatus::Offline => summary.offline_models += 1,
+                ModelStatus::Unknown => summary.unknown_models += 1,
+            }
+            if summary.oldest_check.is_none_or(|oldest| model.last_check < oldest) {
+                summary.oldest_check = Some(model.last_check);
+            }
+            if model.status == ModelStatus::Unknown {
+                continue;
+            }
+            
+            score_sum += model.performance_score;
+            scored += 1;
+            if summary.worst_performer.as_ref().is_none_or(|worst| model.performance_score < worst.score) {
+                summary.worst_performer = Some(ModelScore {
+                    id: model.id,
+                    name: model.name.clone(),
+                    score: model.performance_score,
+                });
+            }
+        }
+        
+        if scored > 0 {
+            summary.average_performance = score_sum / scored as f64;
+        }
+        summary
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Aggregate health report computed from a single published snapshot.
+    ///
+    /// With `Some(tag)` only models carrying that tag are included.
+    pub async fn health_summary(&self, tag: Option<&str>) -> HealthSummary {
+        let state = self.state.load();
+        HealthSummary::from_models(
+            state
+                .monitored_models
+                .values()
+                .map(Arc::as_ref)
+                .filter(|m| tag.is_none_or(|tag| m.tags.contains(tag))),
+        )
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Number of models in each status, with an entry for every status even
+    /// when no model has it. Counts from the current snapshot without
+    /// cloning any model; soft-deleted models are left out.
+    pub async fn count_by_status(&self) -> BTreeMap<ModelStatus, usize> {
+        let mut counts: BTreeMap<ModelStatus, usize> = ModelStatus::ALL.into_iter().map(|status| (status, 0)).collect();
+        for model in self.state.load().monitored_models.values().filter(|m| m.deleted_at.is_none()) {
+            *counts.entry(model.status.clone()).or_default() += 1;
+        }
+        counts
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Overall fleet health in the range 0.0-1.0, where a critical model
+    /// counts for far more than a slightly degraded one.
+    ///
+    /// `health = Σ wᵢ·hᵢ / Σ wᵢ` over every model with a known status, where
+    /// `hᵢ = performance_score × factor(status)` and
+    /// `wᵢ = importance × weight(status)`:
+    ///
+    /// | status   | factor | weight |
+    /// |----------|--------|--------|
+    /// | Healthy  | 1.0    | 1      |
+    /// | Degraded | 0.75   | 2      |
+    /// | Critical | 0.25   | 5      |
+    /// | Offline  | 0.0    | 5      |
+    ///
+    /// `Unknown` and soft-deleted models are left out. 1.0 when no model
+    /// contributes.
+    pub async fn system_health_score(&self) -> f64 {
+        let state = self.state.load();
+        let (mut weighted, mut total_weight) = (0.0, 0.0);
+        for model in state.monitored_models.values().filter(|m| m.deleted_at.is_none()) {
+            let Some((factor, weight)) = severity(&model.status) else {
+                continue;
+            };
+            let weight = model.importance as f64 * weight;
+            weighted += weight * model.performance_score * factor;
+            total_weight += weight;
+        }
+        
+        if total_weight > 0.0 {
+            weighted / total_weight
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Score factor and weight of a status in `system_health_score`
+fn severity(status: &ModelStatus) -> Option<(f64, f64)> {
+    match status {
+        ModelStatus::Healthy => Some((1.0, 1.0)),
+        ModelStatus::Degraded => Some((0.75, 2.0)),
+        ModelStatus::Critical => Some((0.25, 5.0)),
+        ModelStatus::Offline => Some((0.0, 5.0)),
+        ModelStatus::Unknown => None,
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Percentiles of the current `performance_score` across all models
+    /// with a real score, i.e. neither `Unknown` nor soft-deleted,
+    /// keyed by the requested percentile rounded to a whole number (`90.0` -> `90`).
+    ///
+    /// Percentiles are given in the range 0-100; other values are ignored.
+    /// Uses linear interpolation between closest ranks: for `n` sorted scores
+    /// the p-th percentile sits at rank `p / 100 * (n - 1)`, blending the two
+    /// neighbouring scores by the fractional part. Empty if there are no models.
+    pub async fn score_percentiles(&self, percentiles: &[f64]) -> BTreeMap<u32, f64> {
+        let mut scores: Vec<f64> = {
+            let state = self.state.load();
+            state
+                .monitored_models
+                .values()
+                .filter(|m| m.deleted_at.is_none() && m.status != ModelStatus::Unknown)
+                .map(|m| m.performance_score)
+                .collect()
+        };
+        if scores.is_empty() {
+            return BTreeMap::new();
+        }
+        scores.sort_by(f64::total_cmp);
+        
+        percentiles
+            .iter()
+            .filter(|p| (0.0..=100.0).contains(*p))
+            .map(|&p| (p.round() as u32, interpolate(&scores, p)))
+            .collect()
+    }
+}
+
+/// Which end of the score range `top_n` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ranking {
+    /// Lowest scores first
+    Worst,
+    /// Highest scores first
+    Best,
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Up to `n` unpaused models with the lowest `performance_score`, worst first
+    pub async fn top_n_worst(&self, n: usize) -> Vec<MonitoredModel> {
+        self.top_n(n, Ranking::Worst, false).await
+    }
+    
+    /// Up to `n` unpaused models with the highest `performance_score`, best first
+    pub async fn top_n_best(&self, n: usize) -> Vec<MonitoredModel> {
+        self.top_n(n, Ranking::Best, false).await
+    }
+    
+    /// Up to `n` models from the given end of the score range, ties broken by
+    /// name. Paused models are only considered with `include_paused`.
+    ///
+    /// Only the selected models are sorted, not the whole fleet.
+    pub async fn top_n(&self, n: usize, ranking: Ranking, include_paused: bool) -> Vec<MonitoredModel> {
+        let state = self.state.load();
+        let mut models: Vec<&MonitoredModel> = state
+            .monitored_models
+            .values()
+            .map(Arc::as_ref)
+            .filter(|m| include_paused || !m.paused)
+            .collect();
+        
+        let order = |a: &&MonitoredModel, b: &&MonitoredModel| {
+            let by_score = match ranking {
+                Ranking::Worst => a.performance_score.total_cmp(&b.performance_score),
+                Ranking::Best => b.performance_score.total_cmp(&a.performance_score),
+            };
+            by_score.then_with(|| a.name.cmp(&b.name))
+        };
+        if n < models.len() {
+            models.select_nth_unstable_by(n, order);
+            models.truncate(n);
+        }
+        models.sort_by(order);
+        models.into_iter().cloned().collect()
+    }
+    
+    /// Models whose `performance_score` lies in `min..=max`, lowest score
+    /// first with ties broken by name
+    pub async fn models_in_range(&self, min: f64, max: f64) -> Result<Vec<MonitoredModel>, Error> {
+        if !(0.0..=1.0).contains(&min) || !(0.0..=1.0).contains(&max) || min > max {
+            return Err(Error::InvalidScoreRange { min, max });
+        }
+        
+        let state = self.state.load();
+        let mut models: Vec<MonitoredModel> = state
+            .monitored_models
+            .values()
+            .map(Arc::as_ref)
+            .filter(|m| (min..=max).contains(&m.performance_score))
+            .cloned()
+            .collect();
+        models.sort_by(|a, b| a.performance_score.total_cmp(&b.performance_score).then_with(|| a.name.cmp(&b.name)));
+        Ok(models)
+    }
+}
+
+/// Linearly interpolated percentile `p` (0-100) of non-empty sorted `scores`
+fn interpolate(scores: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (scores.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - lower as f64;
+    scores[lower] + (scores[upper] - scores[lower]) * fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+
+    #[tokio::test]
+    async fn test_health_summary() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), ModelType::LLM).await.unwrap();
+        let b = plugin.add_model("b".to_string(), ModelType::LLM).await.unwrap();
+        let c = plugin.add_model("c".to_string(), ModelType::LLM).await.unwrap();
+        plugin.add_model("unchecked".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(a, 0.9).await.unwrap();
+        plugin.record_performance(b, 0.8).await.unwrap();
+        plugin.record_performance(c, 0.2).await.unwrap();
+        
+        let summary = plugin.health_summary(None).await;
+        assert_eq!(summary.total_models, 4);
+        assert_eq!(summary.healthy_models, 1);
+        assert_eq!(summary.degraded_models, 1);
+        assert_eq!(summary.critical_models, 1);
+        assert_eq!(summary.unknown_models, 1);
+        // The unchecked model's placeholder score isn't averaged in
+        assert!((summary.average_performance - 1.9 / 3.0).abs() < 1e-6);
+        assert!((plugin.snapshot().await.system_metrics.average_performance - 1.9 / 3.0).abs() < 1e-6);
+        
+        let worst = summary.worst_performer.unwrap();
+        assert_eq!((worst.id, worst.name.as_str(), worst.score), (c, "c", 0.2));
+        
+        let oldest = plugin.state.read().await.monitored_models.values().map(|m| m.last_check).min();
+        assert_eq!(summary.oldest_check, oldest);
+    }
+
+    #[tokio::test]
+    async fn test_health_summary_empty() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let summary = plugin.health_summary(None).await;
+        assert_eq!(summary.total_models, 0);
+        assert_eq!(summary.average_performance, 0.0);
+        assert!(summary.worst_performer.is_none());
+        assert!(summary.oldest_check.is_none());
+    }
+
+    #[test]
+    fn test_average_of_many_scores_stays_precise() {
+        // Summed in f32 the average of these drifts by around 1e-4
+        let models: Vec<MonitoredModel> = (0..100_000)
+            .map(|i| crate::test_support::sample_model(&format!("m{}", i), 0.1, ModelStatus::Healthy))
+            .collect();
+        
+        let summary = HealthSummary::from_models(&models);
+        assert!((summary.average_performance - 0.1).abs() < 1e-12, "{}", summary.average_performance);
+        assert!((crate::SystemMetrics::from_models(&models).average_performance - 0.1).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_count_by_status() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let empty = plugin.count_by_status().await;
+        assert_eq!(empty.len(), ModelStatus::ALL.len());
+        assert!(empty.values().all(|count| *count == 0));
+        
+        for (name, score) in [("a", Some(0.9)), ("b", Some(0.95)), ("c", Some(0.7)), ("d", Some(0.1)), ("e", None)] {
+            let id = plugin.add_model(name.to_string(), ModelType::LLM).await.unwrap();
+            if let Some(score) = score {
+                plugin.record_performance(id, score).await.unwrap();
+            }
+        }
+        
+        let counts = plugin.count_by_status().await;
+        let expected = BTreeMap::from([
+            (ModelStatus::Unknown, 1),
+            (ModelStatus::Healthy, 2),
+            (ModelStatus::Degraded, 1),
+            (ModelStatus::Critical, 1),
+            (ModelStatus::Offline, 0),
+        ]);
+        assert_eq!(counts, expected);
+    }
+
+    #[tokio::test]
+    async fn test_health_summary_scoped_to_tag() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let prod = plugin.add_model("prod".to_string(), ModelType::LLM).await.unwrap();
+        let staging = plugin.add_model("staging".to_string(), ModelType::LLM).await.unwrap();
+        plugin.add_tag(prod, "production").await.unwrap();
+        plugin.record_performance(prod, 0.9).await.unwrap();
+        plugin.record_performance(staging, 0.2).await.unwrap();
+        
+        let summary = plugin.health_summary(Some("production")).await;
+        assert_eq!(summary.total_models, 1);
+        assert_eq!(summary.healthy_models, 1);
+        assert_eq!(summary.worst_performer.unwrap().id, prod);
+        
+        assert_eq!(plugin.health_summary(Some("missing")).await.total_models, 0);
+        assert_eq!(plugin.health_summary(None).await.total_models, 2);
+    }
+
+    #[tokio::test]
+    async fn test_score_percentiles() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        assert!(plugin.score_percentiles(&[50.0]).await.is_empty());
+        
+        for (i, score) in [0.5, 0.1, 0.9, 0.3, 0.7].into_iter().enumerate() {
+            let id = plugin.add_model(format!("model-{}", i), ModelType::LLM).await.unwrap();
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        
+        // Sorted: 0.1 0.3 0.5 0.7 0.9, ranks 0-4
+        let percentiles = plugin.score_percentiles(&[0.0, 50.0, 90.0, 99.0, 100.0, 150.0]).await;
+        let expected = [(0, 0.1), (50, 0.5), (90, 0.82), (99, 0.892), (100, 0.9)];
+        assert_eq!(percentiles.len(), expected.len());
+        for (p, value) in expected {
+            assert!((percentiles[&p] - value).abs() < 1e-5, "p{} = {}", p, percentiles[&p]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_critical_model_dominates_system_health_score() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        assert_eq!(plugin.system_health_score().await, 1.0);
+        
+        for i in 0..9 {
+            let id = plugin.add_model(format!("healthy-{}", i), ModelType::LLM).await.unwrap();
+            plugin.record_performance(id, 0.95).await.unwrap();
+        }
+        let all_healthy = plugin.system_health_score().await;
+        assert!((all_healthy - 0.95).abs() < 1e-6);
+        
+        let critical = plugin.add_model("critical".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(critical, 0.5).await.unwrap();
+        
+        // The plain mean barely moves, the health score drops sharply
+        let mean = plugin.health_summary(None).await.average_performance;
+        assert!(mean > 0.9);
+        let score = plugin.system_health_score().await;
+        assert!(score < 0.7, "score was {}", score);
+        
+        // An importance of 0 leaves the critical model out entirely
+        plugin.set_model_importance(critical, 0.0).await.unwrap();
+        assert!((plugin.system_health_score().await - all_healthy).abs() < 1e-6);
+        assert!(plugin.set_model_importance(critical, -1.0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_health_summary_counts_paused_separately() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let a = plugin.add_model("a".to_string(), ModelType::LLM).await.unwrap();
+        let b = plugin.add_model("b".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(a, 0.9).await.unwrap();
+        plugin.record_performance(b, 0.2).await.unwrap();
+        plugin.pause_model(b).await.unwrap();
+        
+        let summary = plugin.health_summary(None).await;
+        assert_eq!(summary.total_models, 2);
+        assert_eq!(summary.paused_models, 1);
+        assert_eq!(summary.critical_models, 0);
+        assert!((summary.average_performance - 0.9).abs() < 1e-6);
+        assert_eq!(summary.worst_performer.unwrap().id, a);
+    }
+
+    #[tokio::test]
+    async fn test_top_n_worst_and_best() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let mut ids = BTreeMap::new();
+        for (name, score) in [("a", 0.5), ("b", 0.1), ("c", 0.9), ("d", 0.3), ("e", 0.7)] {
+            let id = plugin.add_model(name.to_string(), ModelType::LLM).await.unwrap();
+            plugin.record_performance(id, score).await.unwrap();
+            ids.insert(name, id);
+        }
+        plugin.pause_model(ids["b"]).await.unwrap();
+        let names = |models: Vec<MonitoredModel>| models.into_iter().map(|m| m.name).collect::<Vec<_>>();
+        
+        assert_eq!(names(plugin.top_n_worst(2).await), vec!["d", "a"]);
+        assert_eq!(names(plugin.top_n_best(2).await), vec!["c", "e"]);
+        assert_eq!(names(plugin.top_n(2, Ranking::Worst, true).await), vec!["b", "d"]);
+        assert!(plugin.top_n_worst(0).await.is_empty());
+        
+        // Asking for more than the fleet returns everything, still ordered
+        assert_eq!(names(plugin.top_n_worst(50).await), vec!["d", "a", "e", "c"]);
+        assert_eq!(names(plugin.top_n(50, Ranking::Best, true).await), vec!["c", "e", "a", "d", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_models_in_range_is_inclusive() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        for (name, score) in [("low", 0.69), ("min", 0.7), ("mid", 0.8), ("max", 0.85), ("high", 0.86)] {
+            let id = plugin.add_model(name.to_string(), ModelType::LLM).await.unwrap();
+            plugin.record_performance(id, score).await.unwrap();
+        }
+        
+        let names: Vec<String> = plugin.models_in_range(0.7, 0.85).await.unwrap().into_iter().map(|m| m.name).collect();
+        assert_eq!(names, vec!["min", "mid", "max"]);
+        
+        let exact = plugin.models_in_range(0.8, 0.8).await.unwrap();
+        assert_eq!(exact.len(), 1);
+        assert_eq!(exact[0].name, "mid");
+    }
+
+    #[tokio::test]
+    async fn test_models_in_range_rejects_bad_bounds() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        for (min, max) in [(0.9, 0.1), (-0.1, 0.5), (0.5, 1.1), (f64::NAN, 0.5)] {
+            let err = plugin.models_in_range(min, max).await.unwrap_err();
+            assert!(matches!(err, Error::InvalidScoreRange { .. }));
+        }
+    }
+}
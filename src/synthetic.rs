@@ -0,0 +1,197 @@
+//! Synthetic score generation for load testing (enabled with the `synthetic` feature)
+//!
+//! A `SyntheticGenerator` draws scores for one model from a
+//! `ScoreDistribution` and feeds them to `record_performance` on a timer,
+//! exercising the same status, alerting and remediation path as real
+//! traffic. Generators are seedable, so a fixed seed replays the same
+//! sequence of scores.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin};
+
+/// Shape of the scores a `SyntheticGenerator` produces
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScoreDistribution {
+    /// Scores scattered normally around a fixed `mean`
+    Normal { mean: f64, std_dev: f64 },
+    /// Scores falling (or rising) linearly from `from` to `to` over `steps`
+    /// samples, then holding at `to`, with normal noise of `std_dev` on top
+    Ramp {
+        from: f64,
+        to: f64,
+        steps: u32,
+        std_dev: f64,
+    },
+}
+
+/// Produces synthetic scores for a single model
+pub struct SyntheticGenerator {
+    model: Uuid,
+    distribution: ScoreDistribution,
+    noise: Normal<f64>,
+    rng: StdRng,
+    step: u32,
+}
+
+impl SyntheticGenerator {
+    /// Generator for `model` drawing from `distribution`, randomly seeded.
+    ///
+    /// Fails with `InvalidScore` if a mean or ramp endpoint is outside
+    /// 0.0-1.0, or `InvalidDistribution` for a negative or non-finite
+    /// standard deviation.
+    pub fn new(model: Uuid, distribution: ScoreDistribution) -> Result<Self, Error> {
+        let (centers, std_dev) = match &distribution {
+            ScoreDistribution::Normal { mean, std_dev } => (vec![*mean], *std_dev),
+            ScoreDistribution::Ramp { from, to, std_dev, .. } => (vec![*from, *to], *std_dev),
+        };
+        if let Some(bad) = centers.into_iter().find(|c| !(0.0..=1.0).contains(c)) {
+            return Err(Error::InvalidScore(bad));
+        }
+        // `Normal` itself accepts a negative deviation and mirrors it
+        let noise = Normal::new(0.0, std_dev)
+            .ok()
+            .filter(|_| std_dev >= 0.0)
+            .ok_or_else(|| Error::InvalidDistribution(format!("standard deviation {} must be finite and non-negative", std_dev)))?;
+        
+        Ok(Self {
+            model,
+            distribution,
+            noise,
+            rng: StdRng::from_os_rng(),
+            step: 0,
+        })
+    }
+    
+    /// Reseed the generator so it produces a reproducible sequence
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+    
+    /// Draw the next score, clamped to 0.0-1.0
+    pub fn next_score(&mut self) -> f64 {
+        let center = match self.distribution {
+            ScoreDistribution::Normal { mean, .. } => mean,
+            ScoreDistribution::Ramp { from, to, steps, .. } => {
+                let progress = if steps == 0 { 1.0 } else { f64::from(self.step.min(steps)) / f64::from(steps) };
+                from + (to - from) * progress
+            }
+        };
+        self.step = self.step.saturating_add(1);
+        (center + self.noise.sample(&mut self.rng)).clamp(0.0, 1.0)
+    }
+    
+    /// Record a score for the model every `period` in the background.
+    ///
+    /// Ticks while the model is paused are skipped. Stops when the model is
+    /// removed or deleted, or the plugin shuts down.
+    pub fn spawn(mut self, plugin: &ModelPerformanceMonitoringPlugin, period: Duration) -> JoinHandle<()> {
+        let plugin = plugin.clone();
+        tokio::spawn(async move {
+            info!(model_id = %self.model, "Generating synthetic scores every {:?}", period);
+            let mut ticks = tokio::time::interval(period);
+            loop {
+                tokio::select! {
+                    _ = ticks.tick() => {}
+                    _ = plugin.stopping() => break,
+                }
+                
+                let score = self.next_score();
+                match plugin.record_performance(self.model, score).await {
+                    Ok(status) => debug!(model_id = %self.model, "Synthetic score {:.3} -> {:?}", score, status),
+                    Err(Error::ModelPaused(_)) => {}
+                    Err(e) => {
+                        warn!(model_id = %self.model, "Stopping synthetic scores: {}", e);
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelStatus;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_degradation_ramp_drives_model_critical() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("synthetic".to_string(), "llm".to_string()).await.unwrap();
+        let ramp = ScoreDistribution::Ramp {
+            from: 0.95,
+            to: 0.2,
+            steps: 20,
+            std_dev: 0.02,
+        };
+        let generator = SyntheticGenerator::new(id, ramp).unwrap().with_seed(42);
+        
+        let handle = generator.spawn(&plugin, Duration::from_secs(1));
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Healthy);
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert_eq!(plugin.get_model(id).await.unwrap().status, ModelStatus::Critical);
+        
+        plugin.remove_model(id).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), handle).await.unwrap().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stop_monitoring_leaves_generator_running() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("synthetic".to_string(), "llm".to_string()).await.unwrap();
+        let normal = ScoreDistribution::Normal { mean: 0.9, std_dev: 0.0 };
+        let generator = SyntheticGenerator::new(id, normal).unwrap().spawn(&plugin, Duration::from_secs(1));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let monitoring = plugin.start_monitoring();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        
+        plugin.stop_monitoring();
+        tokio::time::timeout(Duration::from_secs(5), monitoring).await.expect("monitoring loop kept running").unwrap();
+        let recorded = plugin.model_history(id).await.unwrap().len();
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert!(plugin.model_history(id).await.unwrap().len() > recorded);
+        
+        // Plugin shutdown stops it
+        let running = plugin.clone();
+        let run = tokio::spawn(async move { running.run().await });
+        plugin.request_shutdown();
+        run.await.unwrap().unwrap();
+        tokio::time::timeout(Duration::from_secs(5), generator).await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_seeded_generators_repeat() {
+        let id = Uuid::new_v4();
+        let normal = ScoreDistribution::Normal { mean: 0.8, std_dev: 0.3 };
+        let mut a = SyntheticGenerator::new(id, normal.clone()).unwrap().with_seed(7);
+        let mut b = SyntheticGenerator::new(id, normal).unwrap().with_seed(7);
+        
+        let scores: Vec<f64> = (0..100).map(|_| a.next_score()).collect();
+        assert_eq!(scores, (0..100).map(|_| b.next_score()).collect::<Vec<_>>());
+        assert!(scores.iter().all(|s| (0.0..=1.0).contains(s)));
+        assert!(scores.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn test_invalid_distributions_rejected() {
+        let id = Uuid::new_v4();
+        let out_of_range = ScoreDistribution::Normal { mean: 1.5, std_dev: 0.1 };
+        assert!(matches!(SyntheticGenerator::new(id, out_of_range), Err(Error::InvalidScore(_))));
+        let negative = ScoreDistribution::Ramp {
+            from: 0.9,
+            to: 0.1,
+            steps: 5,
+            std_dev: -1.0,
+        };
+        assert!(matches!(SyntheticGenerator::new(id, negative), Err(Error::InvalidDistribution(_))));
+    }
+}
@@ -0,0 +1,158 @@
+//! Shared helpers for unit tests
+
+use chrono::Utc;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::{ModelStatus, ModelType, MonitoredModel};
+
+/// A standalone model for tests that don't need a plugin
+pub fn sample_model(name: &str, score: f64, status: ModelStatus) -> MonitoredModel {
+    let now = Utc::now();
+    MonitoredModel {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        model_type: ModelType::LLM,
+        status,
+        created_at: now,
+        last_check: now,
+        performance_score: score,
+        history: Default::default(),
+        last_remediation: None,
+        threshold_override: None,
+        tags: Default::default(),
+        metadata: Default::default(),
+        status_since: now,
+        status_durations: Default::default(),
+        importance: 1.0,
+        paused: false,
+        check_interval_override: None,
+        consecutive_healthy: 0,
+        depends_on: Vec::new(),
+        dependency_degraded: false,
+        deleted_at: None,
+        notes: Vec::new(),
+        external_id: None,
+        fleet: None,
+        missed_checks: 0,
+    }
+}
+
+/// Log output captured by [`capture_logs`]
+#[derive(Clone, Default)]
+pub struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    pub fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Route this thread's tracing output into a buffer until the guard is dropped.
+///
+/// Only covers the current thread, so use it from a current-thread runtime.
+pub fn capture_logs() -> (tracing::subscriber::DefaultGuard, CapturedLogs) {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_max_level(tracing::Level::INFO)
+        .with_writer(move || writer.clone())
+        .finish();
+    (tracing::subscriber::set_default(subscriber), logs)
+}
+
+/// A request captured by [`mock_http_server`]
+#[derive(Debug)]
+pub struct CapturedRequest {
+    pub request_line: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl CapturedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Start a bare HTTP/1.1 server that answers every request with `status`
+/// and forwards the captured requests to the returned channel
+pub async fn mock_http_server(status: u16) -> (SocketAddr, mpsc::UnboundedReceiver<CapturedRequest>) {
+    mock_http_response(status, String::new()).await
+}
+
+/// Like [`mock_http_server`], answering every request with `body` as JSON
+pub async fn mock_http_response(status: u16, body: String) -> (SocketAddr, mpsc::UnboundedReceiver<CapturedRequest>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::unbounded_channel();
+    
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let tx = tx.clone();
+            let response_body = body.clone();
+            tokio::spawn(async move {
+                let (read, mut write) = stream.into_split();
+                let mut reader = BufReader::new(read);
+                
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).await.unwrap();
+                
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).await.unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some((key, value)) = line.split_once(':') {
+                        headers.push((key.trim().to_string(), value.trim().to_string()));
+                    }
+                }
+                
+                let length = headers
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.parse().ok())
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).await.unwrap();
+                
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    response_body.len(),
+                    response_body
+                );
+                let _ = write.write_all(response.as_bytes()).await;
+                let _ = tx.send(CapturedRequest {
+                    request_line: request_line.trim_end().to_string(),
+                    headers,
+                    body: String::from_utf8_lossy(&body).into_owned(),
+                });
+            });
+        }
+    });
+    
+    (addr, rx)
+}
@@ -0,0 +1,176 @@
+//! In-memory time-series storage for trend charts
+//!
+//! Unlike the bounded per-model history, samples here are kept for a fixed
+//! retention period and can be queried over arbitrary time ranges.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+use crate::{Error, ModelPerformanceMonitoringPlugin};
+
+/// Score samples per model, ordered by timestamp
+#[derive(Debug, Default)]
+pub struct TimeSeriesStore {
+    series: HashMap<Uuid, VecDeque<(DateTime<Utc>, f64)>>,
+}
+
+impl TimeSeriesStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Append a sample and drop that model's samples older than `retention` before it
+    pub fn record(&mut self, id: Uuid, at: DateTime<Utc>, score: f64, retention: Duration) {
+        let samples = self.series.entry(id).or_default();
+        // Keep the series sorted even if a sample arrives out of order
+        let position = samples.partition_point(|(t, _)| *t <= at);
+        samples.insert(position, (at, score));
+        
+        let cutoff = at - retention;
+        while samples.front().is_some_and(|(t, _)| *t < cutoff) {
+            samples.pop_front();
+        }
+    }
+    
+    /// Forget all samples for a model
+    pub fn remove(&mut self, id: Uuid) {
+        self.series.remove(&id);
+    }
+    
+    /// Samples with `from <= timestamp <= to`, oldest first
+    pub fn query(&self, id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<(DateTime<Utc>, f64)> {
+        let Some(samples) = self.series.get(&id) else {
+            return Vec::new();
+        };
+        let start = samples.partition_point(|(t, _)| *t < from);
+        let end = samples.partition_point(|(t, _)| *t <= to);
+        samples.range(start..end.max(start)).copied().collect()
+    }
+    
+    /// Average score per `bucket`-wide window, keyed by window start.
+    ///
+    /// Windows are aligned to the Unix epoch and empty windows are omitted.
+    pub fn downsample(&self, id: Uuid, bucket: Duration) -> Vec<(DateTime<Utc>, f64)> {
+        let Some(samples) = self.series.get(&id) else {
+            return Vec::new();
+        };
+        let width = bucket.num_milliseconds();
+        if width <= 0 {
+            return Vec::new();
+        }
+        
+        let mut buckets: Vec<(i64, f64, u32)> = Vec::new();
+        for (at, score) in samples {
+            let start = at.timestamp_millis().div_euclid(width) * width;
+            match buckets.last_mut() {
+                Some((current, sum, count)) if *current == start => {
+                    *sum += score;
+                    *count += 1;
+                }
+                _ => buckets.push((start, *score, 1)),
+            }
+        }
+        
+        buckets
+            .into_iter()
+            .filter_map(|(start, sum, count)| {
+                DateTime::from_timestamp_millis(start).map(|at| (at, sum / count as f64))
+            })
+            .collect()
+    }
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Scores recorded for a model between `from` and `to` inclusive
+    pub async fn query_scores(
+        &self,
+        id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<(DateTime<Utc>, f64)>, Error> {
+        self.ensure_model(id).await?;
+        Ok(self.timeseries.read().await.query(id, from, to))
+    }
+    
+    /// Per-bucket average scores for a model, see `TimeSeriesStore::downsample`
+    pub async fn downsample_scores(&self, id: Uuid, bucket: Duration) -> Result<Vec<(DateTime<Utc>, f64)>, Error> {
+        self.ensure_model(id).await?;
+        Ok(self.timeseries.read().await.downsample(id, bucket))
+    }
+    
+    async fn ensure_model(&self, id: Uuid) -> Result<(), Error> {
+        if self.state.load().monitored_models.contains_key(&id) {
+            Ok(())
+        } else {
+            Err(Error::ModelNotFound(id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 - 1_700_000_000 % 3600, 0).unwrap() + Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn test_query_range() {
+        let id = Uuid::new_v4();
+        let mut store = TimeSeriesStore::new();
+        for minute in 0..10 {
+            store.record(id, at(minute), minute as f64 / 10.0, Duration::hours(1));
+        }
+        
+        let samples = store.query(id, at(3), at(5));
+        assert_eq!(samples, vec![(at(3), 0.3), (at(4), 0.4), (at(5), 0.5)]);
+        assert!(store.query(id, at(20), at(30)).is_empty());
+        assert!(store.query(Uuid::new_v4(), at(0), at(10)).is_empty());
+    }
+
+    #[test]
+    fn test_downsample() {
+        let id = Uuid::new_v4();
+        let mut store = TimeSeriesStore::new();
+        for (minute, score) in [(0, 0.2), (5, 0.4), (10, 0.6), (20, 0.8), (25, 1.0)] {
+            store.record(id, at(minute), score, Duration::hours(1));
+        }
+        
+        let buckets = store.downsample(id, Duration::minutes(10));
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets[0].0, at(0));
+        assert!((buckets[0].1 - 0.3).abs() < 1e-6);
+        assert_eq!(buckets[1], (at(10), 0.6));
+        assert_eq!(buckets[2].0, at(20));
+        assert!((buckets[2].1 - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_retention_drops_old_samples() {
+        let id = Uuid::new_v4();
+        let mut store = TimeSeriesStore::new();
+        store.record(id, at(0), 0.5, Duration::minutes(30));
+        store.record(id, at(20), 0.6, Duration::minutes(30));
+        store.record(id, at(45), 0.7, Duration::minutes(30));
+        
+        assert_eq!(store.query(id, at(0), at(60)), vec![(at(20), 0.6), (at(45), 0.7)]);
+    }
+
+    #[tokio::test]
+    async fn test_record_performance_feeds_store() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        let id = plugin.add_model("charted".to_string(), ModelType::LLM).await.unwrap();
+        plugin.record_performance(id, 0.9).await.unwrap();
+        plugin.record_performance_batch(vec![(id, 0.8)]).await;
+        
+        let now = Utc::now();
+        let samples = plugin.query_scores(id, now - Duration::hours(1), now).await.unwrap();
+        assert_eq!(samples.iter().map(|(_, s)| *s).collect::<Vec<_>>(), vec![0.9, 0.8]);
+        
+        plugin.remove_model(id).await.unwrap();
+        assert!(plugin.query_scores(id, now - Duration::hours(1), now).await.is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! Interactive terminal menu
+//!
+//! `run_ui` reads numbered commands from stdin until the user picks Exit or
+//! input ends (e.g. when stdin is piped or `/dev/null`).
+
+use anyhow::Result;
+use std::io::Write;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use crate::ModelPerformanceMonitoringPlugin;
+
+/// A parsed line of menu input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UiCommand {
+    AddModel,
+    Dashboard,
+    PricingTiers,
+    Exit,
+    /// Anything that isn't a menu number; the menu is shown again
+    Invalid(String),
+}
+
+/// Map a line of input to a menu command
+pub fn parse_command(line: &str) -> UiCommand {
+    match line.trim() {
+        "1" => UiCommand::AddModel,
+        "2" => UiCommand::Dashboard,
+        "3" => UiCommand::PricingTiers,
+        "4" => UiCommand::Exit,
+        other => UiCommand::Invalid(other.to_string()),
+    }
+}
+
+/// Why the menu loop stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiExit {
+    /// The user chose Exit
+    Quit,
+    /// Input was closed
+    EndOfInput,
+}
+
+impl ModelPerformanceMonitoringPlugin {
+    /// Run the menu on stdin/stdout. Choosing Exit requests plugin shutdown;
+    /// closed input only ends the menu and leaves monitoring running.
+    pub(crate) async fn run_ui(&self) -> Result<()> {
+        if self.run_menu(stdin_lines(), std::io::stdout()).await? == UiExit::Quit {
+            self.request_shutdown();
+        }
+        Ok(())
+    }
+    
+    pub(crate) async fn run_menu<R, W>(&self, input: R, mut out: W) -> Result<UiExit>
+    where
+        R: AsyncBufRead + Unpin,
+        W: Write,
+    {
+        let mut lines = input.lines();
+        
+        writeln!(out, "=== AdiOS Model Performance Monitoring Plugin ===")?;
+        writeln!(out, "Enterprise model performance monitoring and auto-improvement")?;
+        
+        loop {
+            write_menu(&mut out)?;
+            let Some(line) = lines.next_line().await? else {
+                return Ok(UiExit::EndOfInput);
+            };
+            
+            match parse_command(&line) {
+                UiCommand::AddModel => {
+                    write!(out, "Model name: ")?;
+                    out.flush()?;
+                    let Some(name) = lines.next_line().await? else {
+                        return Ok(UiExit::EndOfInput);
+                    };
+                    write!(out, "Model type (classification, regression, llm, embedding): ")?;
+                    out.flush()?;
+                    let Some(model_type) = lines.next_line().await? else {
+                        return Ok(UiExit::EndOfInput);
+                    };
+                    
+                    match self.add_model(name.trim().to_string(), model_type.trim()).await {
+                        Ok(id) => writeln!(out, "Added model {}", id)?,
+                        Err(e) => writeln!(out, "Could not add model: {}", e)?,
+                    }
+                }
+                UiCommand::Dashboard => {
+                    let summary = self.health_summary(None).await;
+                    writeln!(out, "Models: {}", summary.total_models)?;
+                    writeln!(
+                        out,
+                        "  healthy {}, degraded {}, critical {}, offline {}, unknown {}",
+                        summary.healthy_models,
+                        summary.degraded_models,
+                        summary.critical_models,
+                        summary.offline_models,
+                        summary.unknown_models
+                    )?;
+                    writeln!(out, "Average performance: {:.2}", summary.average_performance)?;
+                    if let Some(worst) = summary.worst_performer {
+                        writeln!(out, "Worst performer: {} ({:.2})", worst.name, worst.score)?;
+                    }
+                }
+                UiCommand::PricingTiers => {
+                    for tier in self.pricing_tiers() {
+                        writeln!(out, "  • {} - ${:.2}/month", tier.name, tier.price as f32 / 100.0)?;
+                    }
+                }
+                UiCommand::Exit => return Ok(UiExit::Quit),
+                UiCommand::Invalid(input) => writeln!(out, "Unknown command '{}'", input)?,
+            }
+        }
+    }
+}
+
+/// Stdin fed through a detached thread.
+///
+/// `tokio::io::stdin` reads on the blocking pool, and the runtime waits for
+/// that read to finish when it shuts down, so a signal would hang until the
+/// next line of input. A plain thread is simply abandoned at exit.
+fn stdin_lines() -> impl AsyncBufRead + Unpin {
+    let (mut writer, reader) = tokio::io::duplex(4096);
+    let handle = tokio::runtime::Handle::current();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lines() {
+            let Ok(line) = line else { break };
+            if handle.block_on(writer.write_all(format!("{}\n", line).as_bytes())).is_err() {
+                break;
+            }
+        }
+    });
+    BufReader::new(reader)
+}
+
+fn write_menu(out: &mut impl Write) -> std::io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "Available commands:")?;
+    writeln!(out, "  1. Add model to monitoring")?;
+    writeln!(out, "  2. View model health dashboard")?;
+    writeln!(out, "  3. Show pricing tiers")?;
+    writeln!(out, "  4. Exit")?;
+    write!(out, "> ")?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelType;
+
+    async fn run_script(plugin: &ModelPerformanceMonitoringPlugin, script: &str) -> (UiExit, String) {
+        let mut out = Vec::new();
+        let exit = plugin.run_menu(script.as_bytes(), &mut out).await.unwrap();
+        (exit, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("1"), UiCommand::AddModel);
+        assert_eq!(parse_command(" 2 \n"), UiCommand::Dashboard);
+        assert_eq!(parse_command("3"), UiCommand::PricingTiers);
+        assert_eq!(parse_command("4"), UiCommand::Exit);
+        assert_eq!(parse_command("five"), UiCommand::Invalid("five".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_menu_adds_model_and_exits() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let (exit, output) = run_script(&plugin, "1\nfraud-detector\nclassification\n2\n4\n").await;
+        assert_eq!(exit, UiExit::Quit);
+        assert!(output.contains("Added model"));
+        assert!(output.contains("Models: 1"));
+        
+        let model = plugin.get_model_by_name("fraud-detector").await.unwrap();
+        assert_eq!(model.model_type, ModelType::Classification);
+    }
+
+    #[tokio::test]
+    async fn test_menu_recovers_from_bad_input() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let (exit, output) = run_script(&plugin, "hello\n1\n \nllm\n3\n").await;
+        assert_eq!(exit, UiExit::EndOfInput);
+        assert!(output.contains("Unknown command 'hello'"));
+        assert!(output.contains("Could not add model: model name must not be empty"));
+        assert!(output.contains("Enterprise"));
+        assert_eq!(output.matches("Available commands:").count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_menu_ends_on_eof_mid_prompt() {
+        let plugin = ModelPerformanceMonitoringPlugin::new().await.unwrap();
+        
+        let (exit, _) = run_script(&plugin, "1\nhalf-entered").await;
+        assert_eq!(exit, UiExit::EndOfInput);
+        assert!(plugin.list_models(None).await.is_empty());
+    }
+}